@@ -5,6 +5,7 @@
 
 use std::{str, fmt, hash};
 use std::cmp::Ordering;
+use std::borrow::Borrow;
 use std::ops::{Add, Sub};
 use std::time::{SystemTime, UNIX_EPOCH};
 use oldtime::Duration as OldDuration;
@@ -448,8 +449,8 @@ impl<Tz: TimeZone> DateTime<Tz> where Tz::Offset: fmt::Display {
 
     /// Formats the combined date and time with the specified formatting items.
     #[inline]
-    pub fn format_with_items<'a, I>(&self, items: I) -> DelayedFormat<I>
-            where I: Iterator<Item=Item<'a>> + Clone {
+    pub fn format_with_items<'a, I, B>(&self, items: I) -> DelayedFormat<I>
+            where I: Iterator<Item=B> + Clone, B: Borrow<Item<'a>> {
         let local = self.naive_local();
         DelayedFormat::new_with_offset(Some(local.date()), Some(local.time()), &self.offset, items)
     }
@@ -0,0 +1,441 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+use std::cmp;
+use oldtime::Duration as OldDuration;
+
+use format::{scan, ParseResult, OUT_OF_RANGE, INVALID, TOO_SHORT, TOO_LONG};
+
+/// Extension trait for additional `Duration` constructors and operations.
+///
+/// `Duration` is re-exported from the `time` crate when the `clock` feature is enabled,
+/// so these methods are provided through a trait which should be brought into scope
+/// (it is also available in the [`prelude`](./prelude/index.html)).
+pub trait DurationExt: Sized {
+    /// Parses a compact, human-written duration like `"2d 3h 15m 30s"` or `"90min"`.
+    ///
+    /// The input is a sequence of `<number><unit>` tokens, optionally separated
+    /// by whitespace, and the resulting duration is the sum of all tokens.
+    /// The number may have a fractional part (`"0.5s"`),
+    /// which is truncated to the whole nanoseconds.
+    /// A leading `-` negates the entire duration.
+    ///
+    /// Supported units are `ns`, `us` (or `µs`), `ms`, `s` (or `sec`),
+    /// `m` (or `min`), `h` (or `hr`), `d` and `w`.
+    ///
+    /// Returns `Err` on the empty input, unknown or missing units,
+    /// and the out-of-range duration.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Duration, DurationExt};
+    ///
+    /// assert_eq!(Duration::from_human_str("30s"), Ok(Duration::seconds(30)));
+    /// assert_eq!(Duration::from_human_str("1h 30m"), Ok(Duration::minutes(90)));
+    /// assert_eq!(Duration::from_human_str("90min"), Ok(Duration::minutes(90)));
+    /// assert_eq!(Duration::from_human_str("0.5s"), Ok(Duration::milliseconds(500)));
+    /// assert_eq!(Duration::from_human_str("-2d 3h"), Ok(-Duration::hours(51)));
+    /// assert!(Duration::from_human_str("3 fortnights").is_err());
+    /// ~~~~
+    fn from_human_str(s: &str) -> ParseResult<Self>;
+
+    /// Parses a duration in any of the following forms, optionally preceded by `-`:
+    ///
+    /// - `H:MM:SS` with an optional fractional second, as printed by
+    ///   [`to_hms_string`](#tymethod.to_hms_string). Hours can exceed 24.
+    /// - Bare seconds with an optional fractional part, like `3600` or `1.5`.
+    /// - ISO 8601 durations with weeks, days, hours, minutes and seconds,
+    ///   like `P1DT2H30M` or `PT0.5S`, as printed by `Duration`'s `Display` implementation.
+    ///   Years and months are not accepted as they have no fixed length.
+    /// - Anything accepted by [`from_human_str`](#tymethod.from_human_str), like `60m`.
+    ///
+    /// Digits finer than a nanosecond are truncated.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Duration, DurationExt};
+    ///
+    /// assert_eq!(Duration::from_str_any("01:30:00"), Ok(Duration::minutes(90)));
+    /// assert_eq!(Duration::from_str_any("-36:00:00.25"),
+    ///            Ok(-Duration::hours(36) - Duration::milliseconds(250)));
+    /// assert_eq!(Duration::from_str_any("3600"), Ok(Duration::hours(1)));
+    /// assert_eq!(Duration::from_str_any("60m"), Ok(Duration::hours(1)));
+    /// assert_eq!(Duration::from_str_any("PT1H"), Ok(Duration::hours(1)));
+    /// assert!(Duration::from_str_any("P1Y").is_err());
+    /// ~~~~
+    fn from_str_any(s: &str) -> ParseResult<Self>;
+
+    /// Returns the duration in the form of `H:MM:SS`, zero-padded to two digits,
+    /// preceded by `-` for negative durations.
+    /// Hours are not wrapped at 24 and any sub-second part is printed
+    /// with 3, 6 or 9 digits as needed.
+    ///
+    /// `Duration`'s own `Display` implementation prints an ISO 8601 duration instead.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Duration, DurationExt};
+    ///
+    /// assert_eq!(Duration::seconds(3661).to_hms_string(), "01:01:01");
+    /// assert_eq!(Duration::hours(-36).to_hms_string(), "-36:00:00");
+    /// assert_eq!(Duration::milliseconds(1500).to_hms_string(), "00:00:01.500");
+    /// assert_eq!(Duration::nanoseconds(7).to_hms_string(), "00:00:00.000000007");
+    /// ~~~~
+    fn to_hms_string(&self) -> String;
+
+    /// Returns the duration if it is positive, or a zero duration otherwise.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Duration, DurationExt};
+    ///
+    /// assert_eq!(Duration::seconds(5).positive_part(), Duration::seconds(5));
+    /// assert_eq!(Duration::seconds(-5).positive_part(), Duration::zero());
+    /// ~~~~
+    fn positive_part(&self) -> Self;
+
+    /// Returns the duration if it is negative, or a zero duration otherwise.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Duration, DurationExt};
+    ///
+    /// assert_eq!(Duration::seconds(5).negative_part(), Duration::zero());
+    /// assert_eq!(Duration::seconds(-5).negative_part(), Duration::seconds(-5));
+    /// ~~~~
+    fn negative_part(&self) -> Self;
+}
+
+impl DurationExt for OldDuration {
+    fn from_human_str(s: &str) -> ParseResult<OldDuration> {
+        // the length of each unit in nanoseconds
+        static UNITS: [(&'static str, i64); 12] = [
+            ("ns", 1), ("us", 1_000), ("\u{b5}s", 1_000), ("ms", 1_000_000),
+            ("s", 1_000_000_000), ("sec", 1_000_000_000),
+            ("m", 60_000_000_000), ("min", 60_000_000_000),
+            ("h", 3_600_000_000_000), ("hr", 3_600_000_000_000),
+            ("d", 86_400_000_000_000), ("w", 604_800_000_000_000),
+        ];
+
+        let mut s = s.trim_left();
+        let negative = s.starts_with('-');
+        if negative {
+            s = s[1..].trim_left();
+        }
+        if s.is_empty() {
+            return Err(TOO_SHORT);
+        }
+
+        let mut total = OldDuration::zero();
+        while !s.is_empty() {
+            // the fractional part is kept as digits until the unit is known
+            let (s_, int, frac) = try!(scan_decimal(s));
+            s = s_.trim_left();
+
+            let unitlen = s.find(|c: char| !c.is_alphabetic()).unwrap_or(s.len());
+            let unit = &s[..unitlen];
+            let scale = match UNITS.iter().find(|&&(name, _)| name == unit) {
+                Some(&(_, scale)) => scale,
+                None => return Err(if s.is_empty() { TOO_SHORT } else { INVALID }),
+            };
+            s = s[unitlen..].trim_left();
+
+            let d = try!(scaled_duration(int, frac, scale));
+            total = try!(total.checked_add(&d).ok_or(OUT_OF_RANGE));
+        }
+
+        Ok(if negative { -total } else { total })
+    }
+
+    fn from_str_any(s: &str) -> ParseResult<OldDuration> {
+        let s = s.trim();
+        let unsigned = if s.starts_with('-') { &s[1..] } else { s };
+
+        let d = if unsigned.contains(':') {
+            try!(parse_hms_duration(unsigned))
+        } else if unsigned.starts_with('P') {
+            try!(parse_iso8601_duration(&unsigned[1..]))
+        } else if !unsigned.is_empty() &&
+                  unsigned.bytes().all(|c| c == b'.' || (b'0' <= c && c <= b'9')) {
+            // bare seconds
+            let (s_, int, frac) = try!(scan_decimal(unsigned));
+            if !s_.is_empty() {
+                return Err(TOO_LONG);
+            }
+            try!(scaled_duration(int, frac, 1_000_000_000))
+        } else {
+            return OldDuration::from_human_str(s);
+        };
+
+        Ok(if unsigned.len() < s.len() { -d } else { d })
+    }
+
+    fn to_hms_string(&self) -> String {
+        let (sign, abs) = if *self < OldDuration::zero() { ("-", -*self) } else { ("", *self) };
+        let secs = abs.num_seconds();
+        let nanos = (abs - OldDuration::seconds(secs)).num_nanoseconds().unwrap_or(0);
+
+        let hms = format!("{}{:02}:{:02}:{:02}", sign, secs / 3600, secs / 60 % 60, secs % 60);
+        if nanos == 0 {
+            hms
+        } else if nanos % 1_000_000 == 0 {
+            format!("{}.{:03}", hms, nanos / 1_000_000)
+        } else if nanos % 1_000 == 0 {
+            format!("{}.{:06}", hms, nanos / 1_000)
+        } else {
+            format!("{}.{:09}", hms, nanos)
+        }
+    }
+
+    fn positive_part(&self) -> OldDuration {
+        cmp::max(*self, OldDuration::zero())
+    }
+
+    fn negative_part(&self) -> OldDuration {
+        cmp::min(*self, OldDuration::zero())
+    }
+}
+
+/// Scans a decimal number `int[.frac]`, returning the integral part and the fractional digits.
+fn scan_decimal(s: &str) -> ParseResult<(&str, i64, &str)> {
+    use std::usize;
+
+    let (mut s, int) = try!(scan::number(s, 1, usize::MAX));
+    let mut frac = "";
+    if s.starts_with('.') {
+        let ndigits = s[1..].find(|c: char| c < '0' || '9' < c).unwrap_or(s.len() - 1);
+        if ndigits == 0 {
+            return Err(INVALID);
+        }
+        frac = &s[1..ndigits + 1];
+        s = &s[ndigits + 1..];
+    }
+    Ok((s, int, frac))
+}
+
+/// Makes a duration of the decimal number `int.frac` in the unit of `scale` nanoseconds.
+/// Digits finer than a nanosecond are truncated.
+fn scaled_duration(int: i64, frac: &str, scale: i64) -> ParseResult<OldDuration> {
+    use std::i64;
+
+    let whole = if scale >= 1_000_000_000 {
+        // `Duration::seconds` panics beyond `i64::MAX` milliseconds
+        let secs = try!(int.checked_mul(scale / 1_000_000_000).ok_or(OUT_OF_RANGE));
+        if secs > i64::MAX / 1000 {
+            return Err(OUT_OF_RANGE);
+        }
+        OldDuration::seconds(secs)
+    } else {
+        OldDuration::nanoseconds(try!(int.checked_mul(scale).ok_or(OUT_OF_RANGE)))
+    };
+
+    let mut nanos = 0;
+    let mut digitscale = scale;
+    for c in frac.bytes() {
+        digitscale /= 10;
+        nanos += i64::from(c - b'0') * digitscale;
+    }
+    whole.checked_add(&OldDuration::nanoseconds(nanos)).ok_or(OUT_OF_RANGE)
+}
+
+/// Parses an unsigned duration in the form of `H:MM:SS[.fff]`, where hours can exceed 24.
+fn parse_hms_duration(s: &str) -> ParseResult<OldDuration> {
+    use std::usize;
+
+    let (s, hours) = try!(scan::number(s, 1, usize::MAX));
+    let s = try!(scan::char(s, b':'));
+    let (s, minutes) = try!(scan::number(s, 2, 2));
+    let s = try!(scan::char(s, b':'));
+    let (s, seconds, frac) = try!(scan_decimal(s));
+    if !s.is_empty() {
+        return Err(TOO_LONG);
+    }
+    if minutes >= 60 || seconds >= 60 {
+        return Err(OUT_OF_RANGE);
+    }
+
+    let hours = try!(scaled_duration(hours, "", 3_600_000_000_000));
+    let rest = try!(scaled_duration(minutes * 60 + seconds, frac, 1_000_000_000));
+    hours.checked_add(&rest).ok_or(OUT_OF_RANGE)
+}
+
+/// Parses an unsigned ISO 8601 duration `PnWnDTnHnMnS` after the leading `P`,
+/// where each component is optional but should be in this order.
+/// Years and months are not accepted as they have no fixed length.
+fn parse_iso8601_duration(s: &str) -> ParseResult<OldDuration> {
+    static DATE_UNITS: [(u8, i64); 2] = [(b'W', 604_800_000_000_000), (b'D', 86_400_000_000_000)];
+    static TIME_UNITS: [(u8, i64); 3] = [(b'H', 3_600_000_000_000), (b'M', 60_000_000_000),
+                                         (b'S', 1_000_000_000)];
+
+    fn components(mut s: &str, mut units: &[(u8, i64)],
+                  total: &mut OldDuration) -> ParseResult<()> {
+        if s.is_empty() {
+            return Err(TOO_SHORT);
+        }
+        while !s.is_empty() {
+            let (s_, int, frac) = try!(scan_decimal(s));
+            let designator = match s_.as_bytes().first() {
+                Some(&c) => c,
+                None => return Err(TOO_SHORT),
+            };
+            // the remaining units are strictly after the current one
+            let idx = try!(units.iter().position(|&(c, _)| c == designator).ok_or(INVALID));
+            let d = try!(scaled_duration(int, frac, units[idx].1));
+            *total = try!(total.checked_add(&d).ok_or(OUT_OF_RANGE));
+            units = &units[idx + 1..];
+            s = &s_[1..];
+        }
+        Ok(())
+    }
+
+    let mut total = OldDuration::zero();
+    match s.find('T') {
+        Some(i) => {
+            if i > 0 {
+                try!(components(&s[..i], &DATE_UNITS, &mut total));
+            }
+            try!(components(&s[i + 1..], &TIME_UNITS, &mut total));
+        }
+        None => try!(components(s, &DATE_UNITS, &mut total)),
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DurationExt;
+    use format::{OUT_OF_RANGE, INVALID, TOO_SHORT, TOO_LONG};
+    use oldtime::Duration as OldDuration;
+
+    #[test]
+    fn test_duration_from_human_str() {
+        let parse = OldDuration::from_human_str;
+
+        assert_eq!(parse("30s"), Ok(OldDuration::seconds(30)));
+        assert_eq!(parse("1h 30m"), Ok(OldDuration::minutes(90)));
+        assert_eq!(parse("1h30m"), Ok(OldDuration::minutes(90)));
+        assert_eq!(parse("90min"), Ok(OldDuration::minutes(90)));
+        assert_eq!(parse("0.5s"), Ok(OldDuration::milliseconds(500)));
+        assert_eq!(parse("2d 3h 15m 30s"),
+                   Ok(OldDuration::days(2) + OldDuration::hours(3) +
+                      OldDuration::minutes(15) + OldDuration::seconds(30)));
+        assert_eq!(parse("1w"), Ok(OldDuration::weeks(1)));
+        assert_eq!(parse("1.5h"), Ok(OldDuration::minutes(90)));
+        assert_eq!(parse("  10 sec  "), Ok(OldDuration::seconds(10)));
+        assert_eq!(parse("2hr"), Ok(OldDuration::hours(2)));
+        assert_eq!(parse("250ms"), Ok(OldDuration::milliseconds(250)));
+        assert_eq!(parse("15us"), Ok(OldDuration::microseconds(15)));
+        assert_eq!(parse("15\u{b5}s"), Ok(OldDuration::microseconds(15)));
+        assert_eq!(parse("7ns"), Ok(OldDuration::nanoseconds(7)));
+        assert_eq!(parse("0.0000000015s"), Ok(OldDuration::nanoseconds(1)));
+        assert_eq!(parse("1.5ns"), Ok(OldDuration::nanoseconds(1)));
+        assert_eq!(parse("0s"), Ok(OldDuration::zero()));
+        assert_eq!(parse("-30s"), Ok(OldDuration::seconds(-30)));
+        assert_eq!(parse("- 1h 30m"), Ok(OldDuration::minutes(-90)));
+
+        assert_eq!(parse(""), Err(TOO_SHORT));
+        assert_eq!(parse("-"), Err(TOO_SHORT));
+        assert_eq!(parse("30"), Err(TOO_SHORT));
+        assert_eq!(parse("30x"), Err(INVALID));
+        assert_eq!(parse("s"), Err(INVALID));
+        assert_eq!(parse("1.s"), Err(INVALID));
+        assert_eq!(parse("1h -30m"), Err(INVALID));
+        assert_eq!(parse("1h, 30m"), Err(INVALID));
+        assert_eq!(parse("100000000000000000000s"), Err(OUT_OF_RANGE));
+        assert_eq!(parse("1000000000000000w"), Err(OUT_OF_RANGE));
+    }
+
+    #[test]
+    fn test_duration_from_str_any() {
+        let parse = OldDuration::from_str_any;
+        let hms = |h, m, s| {
+            OldDuration::hours(h) + OldDuration::minutes(m) + OldDuration::seconds(s)
+        };
+
+        assert_eq!(parse("00:00:00"), Ok(OldDuration::zero()));
+        assert_eq!(parse("01:02:03"), Ok(hms(1, 2, 3)));
+        assert_eq!(parse("1:02:03"), Ok(hms(1, 2, 3)));
+        assert_eq!(parse("100:00:00"), Ok(OldDuration::hours(100)));
+        assert_eq!(parse("-01:02:03.5"), Ok(-hms(1, 2, 3) - OldDuration::milliseconds(500)));
+        assert_eq!(parse("  00:00:00.000000001  "), Ok(OldDuration::nanoseconds(1)));
+        assert_eq!(parse("3600"), Ok(OldDuration::hours(1)));
+        assert_eq!(parse("-0.25"), Ok(OldDuration::milliseconds(-250)));
+        assert_eq!(parse("60m"), Ok(OldDuration::hours(1)));
+        assert_eq!(parse("-1h 30m"), Ok(OldDuration::minutes(-90)));
+        assert_eq!(parse("PT0S"), Ok(OldDuration::zero()));
+        assert_eq!(parse("P1W"), Ok(OldDuration::weeks(1)));
+        assert_eq!(parse("P1DT2.5S"), Ok(OldDuration::days(1) + OldDuration::milliseconds(2500)));
+        assert_eq!(parse("P2W3DT4H5M6S"),
+                   Ok(OldDuration::days(17) + hms(4, 5, 6)));
+        assert_eq!(parse("-PT1.5H"), Ok(OldDuration::minutes(-90)));
+
+        assert_eq!(parse(""), Err(TOO_SHORT));
+        assert_eq!(parse("-"), Err(TOO_SHORT));
+        assert_eq!(parse("01:02"), Err(TOO_SHORT));
+        assert_eq!(parse("01:2:03"), Err(INVALID));
+        assert_eq!(parse("01:60:00"), Err(OUT_OF_RANGE));
+        assert_eq!(parse("01:00:60"), Err(OUT_OF_RANGE));
+        assert_eq!(parse("01:00:00:00"), Err(TOO_LONG));
+        assert_eq!(parse("1.2.3"), Err(TOO_LONG));
+        assert_eq!(parse("--5"), Err(INVALID));
+        assert_eq!(parse("P"), Err(TOO_SHORT));
+        assert_eq!(parse("P1DT"), Err(TOO_SHORT));
+        assert_eq!(parse("PT1"), Err(TOO_SHORT));
+        assert_eq!(parse("P1Y"), Err(INVALID));
+        assert_eq!(parse("P1M"), Err(INVALID));
+        assert_eq!(parse("PT1S1M"), Err(INVALID));
+        assert_eq!(parse("P1D1D"), Err(INVALID));
+        assert_eq!(parse("100000000000000:00:00"), Err(OUT_OF_RANGE));
+    }
+
+    #[test]
+    fn test_duration_to_hms_string() {
+        assert_eq!(OldDuration::zero().to_hms_string(), "00:00:00");
+        assert_eq!(OldDuration::seconds(59).to_hms_string(), "00:00:59");
+        assert_eq!(OldDuration::days(2).to_hms_string(), "48:00:00");
+        assert_eq!(OldDuration::milliseconds(-1).to_hms_string(), "-00:00:00.001");
+        assert_eq!(OldDuration::microseconds(1_000_001).to_hms_string(), "00:00:01.000001");
+        assert_eq!(OldDuration::max_value().to_hms_string(), "2562047788015:12:55.807");
+        assert_eq!(OldDuration::min_value().to_hms_string(), "-2562047788015:12:55.808");
+
+        // round-trips pseudo-random durations of various magnitudes
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        for i in 0..100 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let nanos = (seed >> (i % 40)) as i64 * if i % 2 == 0 { 1 } else { -1 };
+            let d = OldDuration::nanoseconds(nanos);
+            assert_eq!(OldDuration::from_str_any(&d.to_hms_string()), Ok(d),
+                       "{}", d.to_hms_string());
+            assert_eq!(OldDuration::from_str_any(&d.to_string()), Ok(d), "{}", d);
+        }
+        let d = OldDuration::max_value();
+        assert_eq!(OldDuration::from_str_any(&d.to_hms_string()), Ok(d));
+        assert_eq!(OldDuration::from_str_any(&d.to_string()), Ok(d));
+        assert_eq!(OldDuration::from_str_any(&(-d).to_hms_string()), Ok(-d));
+    }
+
+    #[test]
+    fn test_duration_positive_negative_part() {
+        let d = OldDuration::milliseconds(1500);
+        assert_eq!(d.positive_part(), d);
+        assert_eq!(d.negative_part(), OldDuration::zero());
+        assert_eq!((-d).positive_part(), OldDuration::zero());
+        assert_eq!((-d).negative_part(), -d);
+        assert_eq!(OldDuration::zero().positive_part(), OldDuration::zero());
+        assert_eq!(OldDuration::zero().negative_part(), OldDuration::zero());
+
+        // the parts always add up to the original duration
+        let durations = [OldDuration::max_value(), OldDuration::min_value(),
+                         OldDuration::nanoseconds(-1)];
+        for &d in &durations {
+            assert_eq!(d.positive_part() + d.negative_part(), d);
+        }
+    }
+}
@@ -18,9 +18,11 @@
 use std::fmt;
 use std::str::FromStr;
 use std::error::Error;
+use std::borrow::Borrow;
 
 use {Datelike, Timelike, Weekday, ParseWeekdayError};
 use div::{div_floor, mod_floor};
+use names::{SHORT_MONTHS, LONG_MONTHS, SHORT_WEEKDAYS, LONG_WEEKDAYS};
 use offset::{Offset, FixedOffset};
 use naive::{NaiveDate, NaiveTime};
 
@@ -323,37 +325,26 @@ impl Error for ParseError {
     }
 }
 
-// to be used in this module and submodules
-const OUT_OF_RANGE: ParseError = ParseError(ParseErrorKind::OutOfRange);
-const IMPOSSIBLE:   ParseError = ParseError(ParseErrorKind::Impossible);
-const NOT_ENOUGH:   ParseError = ParseError(ParseErrorKind::NotEnough);
-const INVALID:      ParseError = ParseError(ParseErrorKind::Invalid);
-const TOO_SHORT:    ParseError = ParseError(ParseErrorKind::TooShort);
-const TOO_LONG:     ParseError = ParseError(ParseErrorKind::TooLong);
-const BAD_FORMAT:   ParseError = ParseError(ParseErrorKind::BadFormat);
+// to be used in this module, submodules and `duration`
+pub(crate) const OUT_OF_RANGE: ParseError = ParseError(ParseErrorKind::OutOfRange);
+pub(crate) const IMPOSSIBLE:   ParseError = ParseError(ParseErrorKind::Impossible);
+pub(crate) const NOT_ENOUGH:   ParseError = ParseError(ParseErrorKind::NotEnough);
+pub(crate) const INVALID:      ParseError = ParseError(ParseErrorKind::Invalid);
+pub(crate) const TOO_SHORT:    ParseError = ParseError(ParseErrorKind::TooShort);
+pub(crate) const TOO_LONG:     ParseError = ParseError(ParseErrorKind::TooLong);
+pub(crate) const BAD_FORMAT:   ParseError = ParseError(ParseErrorKind::BadFormat);
 
 /// Tries to format given arguments with given formatting items.
 /// Internally used by `DelayedFormat`.
-pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Option<&NaiveTime>,
-                     off: Option<&(String, FixedOffset)>, items: I) -> fmt::Result
-        where I: Iterator<Item=Item<'a>> {
-    // full and abbreviated month and weekday names
-    static SHORT_MONTHS: [&'static str; 12] =
-        ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
-    static LONG_MONTHS: [&'static str; 12] =
-        ["January", "February", "March", "April", "May", "June",
-         "July", "August", "September", "October", "November", "December"];
-    static SHORT_WEEKDAYS: [&'static str; 7] =
-        ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
-    static LONG_WEEKDAYS: [&'static str; 7] =
-        ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
-
+pub fn format<'a, I, B>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Option<&NaiveTime>,
+                        off: Option<&(String, FixedOffset)>, items: I) -> fmt::Result
+        where I: Iterator<Item=B>, B: Borrow<Item<'a>> {
     for item in items {
-        match item {
+        match *item.borrow() {
             Item::Literal(s) | Item::Space(s) => try!(write!(w, "{}", s)),
             Item::OwnedLiteral(ref s) | Item::OwnedSpace(ref s) => try!(write!(w, "{}", s)),
 
-            Item::Numeric(spec, pad) => {
+            Item::Numeric(ref spec, ref pad) => {
                 use self::Numeric::*;
 
                 let week_from_sun = |d: &NaiveDate|
@@ -361,7 +352,7 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
                 let week_from_mon = |d: &NaiveDate|
                     (d.ordinal() as i32 - d.weekday().num_days_from_monday() as i32 + 7) / 7;
 
-                let (width, v) = match spec {
+                let (width, v) = match *spec {
                     Year           => (4, date.map(|d| i64::from(d.year()))),
                     YearDiv100     => (2, date.map(|d| div_floor(i64::from(d.year()), 100))),
                     YearMod100     => (2, date.map(|d| mod_floor(i64::from(d.year()), 100))),
@@ -399,15 +390,15 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
                 };
 
                 if let Some(v) = v {
-                    if (spec == Year || spec == IsoYear) && !(0 <= v && v < 10_000) {
+                    if (*spec == Year || *spec == IsoYear) && !(0 <= v && v < 10_000) {
                         // non-four-digit years require an explicit sign as per ISO 8601
-                        match pad {
+                        match *pad {
                             Pad::None => try!(write!(w, "{:+}", v)),
                             Pad::Zero => try!(write!(w, "{:+01$}", v, width + 1)),
                             Pad::Space => try!(write!(w, "{:+1$}", v, width + 1)),
                         }
                     } else {
-                        match pad {
+                        match *pad {
                             Pad::None => try!(write!(w, "{}", v)),
                             Pad::Zero => try!(write!(w, "{:01$}", v, width)),
                             Pad::Space => try!(write!(w, "{:1$}", v, width)),
@@ -418,7 +409,7 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
                 }
             },
 
-            Item::Fixed(spec) => {
+            Item::Fixed(ref spec) => {
                 use self::Fixed::*;
 
                 /// Prints an offset from UTC in the format of `+HHMM` or `+HH:MM`.
@@ -438,7 +429,7 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
                     }
                 }
 
-                let ret = match spec {
+                let ret = match *spec {
                     ShortMonthName =>
                         date.map(|d| write!(w, "{}", SHORT_MONTHS[d.month0() as usize])),
                     LongMonthName =>
@@ -546,7 +537,7 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
 mod parsed;
 
 // due to the size of parsing routines, they are in separate modules.
-mod scan;
+pub(crate) mod scan;
 mod parse;
 
 pub mod strftime;
@@ -565,7 +556,7 @@ pub struct DelayedFormat<I> {
     items: I,
 }
 
-impl<'a, I: Iterator<Item=Item<'a>> + Clone> DelayedFormat<I> {
+impl<'a, I: Iterator<Item=B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
     /// Makes a new `DelayedFormat` value out of local date and time.
     pub fn new(date: Option<NaiveDate>, time: Option<NaiveTime>, items: I) -> DelayedFormat<I> {
         DelayedFormat { date: date, time: time, off: None, items: items }
@@ -580,7 +571,7 @@ impl<'a, I: Iterator<Item=Item<'a>> + Clone> DelayedFormat<I> {
     }
 }
 
-impl<'a, I: Iterator<Item=Item<'a>> + Clone> fmt::Display for DelayedFormat<I> {
+impl<'a, I: Iterator<Item=B> + Clone, B: Borrow<Item<'a>>> fmt::Display for DelayedFormat<I> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         format(f, self.date.as_ref(), self.time.as_ref(), self.off.as_ref(), self.items.clone())
     }
@@ -7,6 +7,7 @@
 #![allow(deprecated)]
 
 use std::usize;
+use std::borrow::Borrow;
 
 use Weekday;
 
@@ -204,14 +205,14 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
 ///   so one can prepend any number of whitespace then any number of zeroes before numbers.
 ///
 /// - (Still) obeying the intrinsic parsing width. This allows, for example, parsing `HHMMSS`.
-pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<()>
-        where I: Iterator<Item=Item<'a>> {
+pub fn parse<'a, I, B>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<()>
+        where I: Iterator<Item=B>, B: Borrow<Item<'a>> {
     macro_rules! try_consume {
         ($e:expr) => ({ let (s_, v) = try!($e); s = s_; v })
     }
 
     for item in items {
-        match item {
+        match *item.borrow() {
             Item::Literal(prefix) => {
                 if s.len() < prefix.len() { return Err(TOO_SHORT); }
                 if !s.starts_with(prefix) { return Err(INVALID); }
@@ -228,11 +229,11 @@ pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<(
                 s = s.trim_left();
             }
 
-            Item::Numeric(spec, _pad) => {
+            Item::Numeric(ref spec, ref _pad) => {
                 use super::Numeric::*;
                 type Setter = fn(&mut Parsed, i64) -> ParseResult<()>;
 
-                let (width, signed, set): (usize, bool, Setter) = match spec {
+                let (width, signed, set): (usize, bool, Setter) = match *spec {
                     Year           => (4, true, Parsed::set_year),
                     YearDiv100     => (2, false, Parsed::set_year_div_100),
                     YearMod100     => (2, false, Parsed::set_year_mod_100),
@@ -275,10 +276,10 @@ pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<(
                 try!(set(parsed, v));
             }
 
-            Item::Fixed(spec) => {
+            Item::Fixed(ref spec) => {
                 use super::Fixed::*;
 
-                match spec {
+                match *spec {
                     ShortMonthName => {
                         let month0 = try_consume!(scan::short_month0(s));
                         try!(parsed.set_month(i64::from(month0) + 1));
@@ -159,6 +159,29 @@ Notes:
 use super::{Item, Numeric, Fixed, InternalFixed, InternalInternal, Pad};
 
 /// Parsing iterator for `strftime`-like format strings.
+///
+/// The format string is parsed lazily every time the items are iterated.
+/// When the same format is used many times, the items can be collected once
+/// and the resulting slice iterated instead;
+/// `format_with_items` and [`parse`](../fn.parse.html) accept iterators
+/// over both `Item` and `&Item`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::format::{self, Item, Parsed, StrftimeItems};
+///
+/// let items: Vec<Item> = StrftimeItems::new("%Y-%m-%d").collect();
+///
+/// let d = NaiveDate::from_ymd(2015, 9, 5);
+/// assert_eq!(d.format_with_items(items.iter()).to_string(), "2015-09-05");
+/// assert_eq!(d.succ().format_with_items(items.iter()).to_string(), "2015-09-06");
+///
+/// let mut parsed = Parsed::new();
+/// format::parse(&mut parsed, "2015-09-07", items.iter()).unwrap();
+/// assert_eq!(parsed.to_naive_date(), Ok(NaiveDate::from_ymd(2015, 9, 7)));
+/// ~~~~
 #[derive(Clone, Debug)]
 pub struct StrftimeItems<'a> {
     /// Remaining portion of the string.
@@ -405,6 +428,27 @@ fn test_strftime_items() {
     assert_eq!(parse_and_collect("%#m"), [Item::Error]);
 }
 
+#[cfg(test)]
+#[test]
+fn test_strftime_items_reuse() {
+    use Timelike;
+    use naive::NaiveDate;
+    use super::{parse, Parsed};
+
+    let items: Vec<Item> = StrftimeItems::new("%a %e %b %Y, %H:%M").collect();
+    let dt = NaiveDate::from_ymd(2001, 7, 8).and_hms(0, 34, 59);
+    for i in 0..3 {
+        let dt = dt + ::oldtime::Duration::days(i);
+        assert_eq!(dt.format_with_items(items.iter()).to_string(),
+                   dt.format("%a %e %b %Y, %H:%M").to_string());
+
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, &dt.format_with_items(items.iter()).to_string(),
+              items.iter()).unwrap();
+        assert_eq!(parsed.to_naive_datetime_with_offset(0), Ok(dt.with_second(0).unwrap()));
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_strftime_docs() {
@@ -423,14 +423,18 @@ pub use oldtime::Duration;
 
 #[cfg(feature="clock")]
 #[doc(no_inline)] pub use offset::Local;
+#[cfg(feature="clock")]
+pub use offset::{utc_now, local_now};
 #[doc(no_inline)] pub use offset::{TimeZone, Offset, LocalResult, Utc, FixedOffset};
 #[doc(no_inline)] pub use naive::{NaiveDate, IsoWeek, NaiveTime, NaiveDateTime};
 pub use date::{Date, MIN_DATE, MAX_DATE};
 pub use datetime::{DateTime, SecondsFormat};
+pub use period::Period;
 #[cfg(feature = "rustc-serialize")]
 pub use datetime::rustc_serialize::TsSeconds;
 pub use format::{ParseError, ParseResult};
 pub use round::SubsecRound;
+pub use duration::DurationExt;
 
 /// A convenience module appropriate for glob imports (`use chrono::prelude::*;`).
 pub mod prelude {
@@ -443,6 +447,7 @@ pub mod prelude {
     #[doc(no_inline)] pub use Date;
     #[doc(no_inline)] pub use {DateTime, SecondsFormat};
     #[doc(no_inline)] pub use SubsecRound;
+    #[doc(no_inline)] pub use DurationExt;
 }
 
 // useful throughout the codebase
@@ -451,6 +456,7 @@ macro_rules! try_opt {
 }
 
 mod div;
+mod names;
 #[cfg(not(feature="clock"))]
 mod oldtime;
 pub mod offset;
@@ -466,11 +472,17 @@ pub mod naive {
     mod isoweek;
     mod time;
     mod datetime;
+    mod date_range;
+    mod calendar;
+    mod diff;
 
-    pub use self::date::{NaiveDate, MIN_DATE, MAX_DATE};
+    pub use self::date::{NaiveDate, MIN_DATE, MAX_DATE, DateParsePreferences};
     pub use self::isoweek::IsoWeek;
     pub use self::time::NaiveTime;
     pub use self::datetime::NaiveDateTime;
+    pub use self::date_range::{DateRange, DateRangeIter};
+    pub use self::calendar::{CalendarDate, JulianDate};
+    pub use self::diff::DateTimeDiff;
     #[cfg(feature = "rustc-serialize")]
     #[allow(deprecated)]
     pub use self::datetime::rustc_serialize::TsSeconds;
@@ -491,8 +503,10 @@ pub mod naive {
 }
 mod date;
 mod datetime;
+mod period;
 pub mod format;
 mod round;
+mod duration;
 
 /// Serialization/Deserialization in alternate formats
 ///
@@ -953,6 +967,42 @@ pub trait Timelike: Sized {
     /// the [leap second](./naive/struct.NaiveTime.html#leap-second-handling).
     fn nanosecond(&self) -> u32;
 
+    /// Returns the number of milliseconds since the whole non-leap second,
+    /// i.e. the sub-second part of the time in milliseconds.
+    /// The range from 1,000 to 1,999 represents
+    /// the [leap second](./naive/struct.NaiveTime.html#leap-second-handling).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveTime, Timelike};
+    ///
+    /// let t = NaiveTime::from_hms_nano(23, 56, 4, 12_345_678);
+    /// assert_eq!(t.millisecond(), 12);
+    /// ~~~~
+    #[inline]
+    fn millisecond(&self) -> u32 {
+        self.nanosecond() / 1_000_000
+    }
+
+    /// Returns the number of microseconds since the whole non-leap second,
+    /// i.e. the sub-second part of the time in microseconds.
+    /// The range from 1,000,000 to 1,999,999 represents
+    /// the [leap second](./naive/struct.NaiveTime.html#leap-second-handling).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveTime, Timelike};
+    ///
+    /// let t = NaiveTime::from_hms_nano(23, 56, 4, 12_345_678);
+    /// assert_eq!(t.microsecond(), 12_345);
+    /// ~~~~
+    #[inline]
+    fn microsecond(&self) -> u32 {
+        self.nanosecond() / 1_000
+    }
+
     /// Makes a new value with the hour number changed.
     ///
     /// Returns `None` when the resulting value would be invalid.
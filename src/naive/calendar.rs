@@ -0,0 +1,264 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Conversion between the proleptic Gregorian calendar and other calendars.
+
+use std::fmt;
+use num_traits::ToPrimitive;
+
+use Datelike;
+use div::div_floor;
+use naive::NaiveDate;
+
+/// A date in some calendar system which can be converted from and to
+/// the proleptic Gregorian calendar used by [`NaiveDate`](./struct.NaiveDate.html).
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::naive::{CalendarDate, JulianDate};
+///
+/// // the first day of the Gregorian calendar, October 15, 1582,
+/// // followed October 4, 1582 in the Julian calendar.
+/// let d = NaiveDate::from_ymd(1582, 10, 15);
+/// assert_eq!(JulianDate::from_gregorian(d), JulianDate::from_ymd(1582, 10, 5));
+/// assert_eq!(JulianDate::from_ymd(1582, 10, 4).to_gregorian(), d.pred());
+/// ~~~~
+pub trait CalendarDate: Sized {
+    /// Makes a new date in this calendar from the Gregorian date.
+    fn from_gregorian(date: NaiveDate) -> Self;
+
+    /// Returns the same day in the proleptic Gregorian calendar.
+    fn to_gregorian(&self) -> NaiveDate;
+}
+
+/// `NaiveDate` itself is trivially a date in the Gregorian calendar.
+impl CalendarDate for NaiveDate {
+    #[inline]
+    fn from_gregorian(date: NaiveDate) -> NaiveDate {
+        date
+    }
+
+    #[inline]
+    fn to_gregorian(&self) -> NaiveDate {
+        *self
+    }
+}
+
+/// A date in the proleptic Julian calendar.
+///
+/// The Julian calendar has a leap year every four years without exception,
+/// and the year 0 is 1 BCE as in `NaiveDate`.
+/// It covers the same range of days as `NaiveDate`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::naive::{CalendarDate, JulianDate};
+///
+/// let d = JulianDate::from_ymd(1900, 2, 29); // not a leap year in the Gregorian calendar
+/// assert_eq!(d.to_gregorian(), NaiveDate::from_ymd(1900, 3, 13));
+/// assert_eq!((d.year(), d.month(), d.day()), (1900, 2, 29));
+/// ~~~~
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct JulianDate {
+    // the same day in the Gregorian calendar, which is also used for the comparison
+    date: NaiveDate,
+}
+
+impl JulianDate {
+    /// Makes a new `JulianDate` from year, month and day in the Julian calendar.
+    ///
+    /// Panics on the out-of-range date, invalid month and/or day.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> JulianDate {
+        JulianDate::from_ymd_opt(year, month, day).expect("invalid or out-of-range date")
+    }
+
+    /// Makes a new `JulianDate` from year, month and day in the Julian calendar.
+    ///
+    /// Returns `None` on the out-of-range date, invalid month and/or day.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::naive::JulianDate;
+    ///
+    /// assert!(JulianDate::from_ymd_opt(1900, 2, 29).is_some());
+    /// assert!(JulianDate::from_ymd_opt(1901, 2, 29).is_none());
+    /// assert!(JulianDate::from_ymd_opt(1900, 4, 31).is_none());
+    /// assert!(JulianDate::from_ymd_opt(400000, 1, 1).is_none());
+    /// ~~~~
+    pub fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<JulianDate> {
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        // the Julian Day Number, with the year shifted to start from March
+        let a = i64::from((14 - month) / 12);
+        let y = i64::from(year) + 4800 - a;
+        let m = i64::from(month) + 12 * a - 3;
+        let jdn = i64::from(day) + (153 * m + 2) / 5 + 365 * y + div_floor(y, 4) - 32_083;
+
+        let days = try_opt!((jdn - JDN_OF_DAY_0_FROM_CE).to_i32());
+        NaiveDate::from_num_days_from_ce_opt(days).map(|date| JulianDate { date: date })
+    }
+
+    /// Returns the year number in the Julian calendar.
+    #[inline]
+    pub fn year(&self) -> i32 {
+        self.ymd().0
+    }
+
+    /// Returns the month number in the Julian calendar, starting from 1.
+    #[inline]
+    pub fn month(&self) -> u32 {
+        self.ymd().1
+    }
+
+    /// Returns the day of month in the Julian calendar, starting from 1.
+    #[inline]
+    pub fn day(&self) -> u32 {
+        self.ymd().2
+    }
+
+    fn ymd(&self) -> (i32, u32, u32) {
+        let jdn = i64::from(self.date.num_days_from_ce()) + JDN_OF_DAY_0_FROM_CE;
+        let c = jdn + 32_082;
+        let d = div_floor(4 * c + 3, 1461);
+        let e = c - div_floor(1461 * d, 4); // the day of the year starting from March 1
+        let m = (5 * e + 2) / 153;
+        let day = e - (153 * m + 2) / 5 + 1;
+        let month = m + 3 - 12 * (m / 10);
+        let year = d - 4800 + m / 10;
+        (year as i32, month as u32, day as u32)
+    }
+}
+
+// the Julian Day Number of December 31, 1 BCE in the Gregorian calendar.
+const JDN_OF_DAY_0_FROM_CE: i64 = 1_721_425;
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl CalendarDate for JulianDate {
+    #[inline]
+    fn from_gregorian(date: NaiveDate) -> JulianDate {
+        JulianDate { date: date }
+    }
+
+    #[inline]
+    fn to_gregorian(&self) -> NaiveDate {
+        self.date
+    }
+}
+
+/// The `Debug` output of the Julian date is in the same format as `NaiveDate`,
+/// followed by ` (Julian)`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::naive::JulianDate;
+///
+/// assert_eq!(format!("{:?}", JulianDate::from_ymd(1582, 10, 4)), "1582-10-04 (Julian)");
+/// assert_eq!(format!("{:?}", JulianDate::from_ymd(-44, 3, 15)), "-0044-03-15 (Julian)");
+/// ~~~~
+impl fmt::Debug for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.ymd();
+        if 0 <= year && year <= 9999 {
+            write!(f, "{:04}-{:02}-{:02} (Julian)", year, month, day)
+        } else {
+            // ISO 8601 requires the explicit sign for out-of-range years
+            write!(f, "{:+05}-{:02}-{:02} (Julian)", year, month, day)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CalendarDate, JulianDate};
+    use Datelike;
+    use naive::{NaiveDate, MIN_DATE, MAX_DATE};
+
+    #[test]
+    fn test_julian_switchovers() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        let julian = |y, m, d| JulianDate::from_ymd(y, m, d);
+
+        // (the last Julian date, the first Gregorian date)
+        let switchovers = [
+            (julian(1582, 10, 4), ymd(1582, 10, 15)), // Papal States, Spain, Portugal
+            (julian(1700, 2, 18), ymd(1700, 3, 1)), // Protestant Germany
+            (julian(1752, 9, 2), ymd(1752, 9, 14)), // Great Britain and its colonies
+            (julian(1918, 1, 31), ymd(1918, 2, 14)), // Russia
+            (julian(1923, 2, 15), ymd(1923, 3, 1)), // Greece
+        ];
+        for &(last_julian, first_gregorian) in &switchovers {
+            assert_eq!(last_julian.to_gregorian().succ(), first_gregorian);
+            assert_eq!(JulianDate::from_gregorian(first_gregorian.pred()), last_julian);
+        }
+    }
+
+    #[test]
+    fn test_julian_from_ymd() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        let julian = |y, m, d| JulianDate::from_ymd(y, m, d);
+
+        assert_eq!(julian(1, 1, 3).to_gregorian(), ymd(1, 1, 1));
+        assert_eq!(julian(1, 1, 2).to_gregorian(), ymd(0, 12, 31));
+        assert_eq!(julian(200, 3, 1).to_gregorian(), ymd(200, 3, 1));
+        assert_eq!(julian(-43, 3, 15).to_gregorian(), ymd(-43, 3, 13)); // 44 BCE
+        assert_eq!(julian(1900, 2, 29).to_gregorian(), ymd(1900, 3, 13));
+        assert_eq!(julian(2000, 1, 1).to_gregorian(), ymd(2000, 1, 14));
+
+        assert!(JulianDate::from_ymd_opt(-1, 2, 29).is_none());
+        assert!(JulianDate::from_ymd_opt(-4, 2, 29).is_some());
+        assert!(JulianDate::from_ymd_opt(2015, 0, 1).is_none());
+        assert!(JulianDate::from_ymd_opt(2015, 13, 1).is_none());
+        assert!(JulianDate::from_ymd_opt(2015, 6, 0).is_none());
+        assert!(JulianDate::from_ymd_opt(2015, 6, 31).is_none());
+        assert!(JulianDate::from_ymd_opt(2015, 7, 31).is_some());
+    }
+
+    #[test]
+    fn test_julian_round_trip() {
+        let mut d = NaiveDate::from_ymd(-401, 1, 1);
+        while d.year() < 401 {
+            let j = JulianDate::from_gregorian(d);
+            assert_eq!(JulianDate::from_ymd_opt(j.year(), j.month(), j.day()), Some(j));
+            assert_eq!(j.to_gregorian(), d);
+            d = d.succ();
+        }
+
+        for &d in &[MIN_DATE, MAX_DATE] {
+            let j = JulianDate::from_gregorian(d);
+            assert_eq!(JulianDate::from_ymd_opt(j.year(), j.month(), j.day()), Some(j));
+        }
+        assert_eq!(NaiveDate::from_gregorian(MAX_DATE), MAX_DATE);
+    }
+
+    #[test]
+    fn test_julian_out_of_range() {
+        let j = JulianDate::from_gregorian(MAX_DATE);
+        let (y, m, d) = (j.year(), j.month(), j.day());
+        let next = if d < 28 {
+            (y, m, d + 1)
+        } else if m < 12 {
+            (y, m + 1, 1)
+        } else {
+            (y + 1, 1, 1)
+        };
+        assert_eq!(JulianDate::from_ymd_opt(next.0, next.1, next.2), None);
+        assert_eq!(JulianDate::from_ymd_opt(i32::max_value(), 1, 1), None);
+        assert_eq!(JulianDate::from_ymd_opt(i32::min_value(), 1, 1), None);
+    }
+}
@@ -3,13 +3,15 @@
 
 //! ISO 8601 calendar date without timezone.
 
-use std::{str, fmt};
+use std::{str, fmt, i32};
+use std::borrow::Borrow;
 use std::ops::{Add, Sub, AddAssign, SubAssign};
 use num_traits::ToPrimitive;
 use oldtime::Duration as OldDuration;
 
 use {Weekday, Datelike};
 use div::div_mod_floor;
+use names::{SHORT_MONTHS, LONG_MONTHS, SHORT_WEEKDAYS, LONG_WEEKDAYS};
 use naive::{NaiveTime, NaiveDateTime, IsoWeek};
 use format::{Item, Numeric, Pad};
 use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItems};
@@ -45,6 +47,13 @@ const MIN_DAYS_FROM_YEAR_0: i32 = (MIN_YEAR + 400_000) * 365 +
 #[cfg(test)] // only used for testing, but duplicated in naive::datetime
 const MAX_BITS: usize = 44;
 
+// the day 0 of Modified Julian Day (November 17, 1858).
+const MJD_EPOCH_DAYS_FROM_CE: i32 = 678_576;
+
+// the day before the Excel serial 1 (December 31, 1899) and the serial of December 31, 9999.
+const EXCEL_EPOCH_DAYS_FROM_CE: i32 = 693_595;
+const EXCEL_MAX_SERIAL: u32 = 2_958_465;
+
 /// ISO 8601 calendar date without timezone.
 /// Allows for every [proleptic Gregorian date](#calendar-date)
 /// from Jan 1, 262145 BCE to Dec 31, 262143 CE.
@@ -98,6 +107,9 @@ pub struct NaiveDate {
     ymdf: DateImpl, // (year << 13) | of
 }
 
+// the `DD.MM.YYYY` format, see `NaiveDate::from_dmy_str`.
+const DMY_FORMAT: &'static str = "%d.%m.%Y";
+
 /// The minimum possible `NaiveDate` (January 1, 262145 BCE).
 pub const MIN_DATE: NaiveDate = NaiveDate { ymdf: (MIN_YEAR << 13) | (1 << 4) | 0o07 /*FE*/ };
 /// The maximum possible `NaiveDate` (December 31, 262143 CE).
@@ -186,6 +198,71 @@ impl NaiveDate {
         NaiveDate::from_mdf(year, Mdf::new(month, day, flags))
     }
 
+    /// Makes a new `NaiveDate` from the calendar date (year, month and day),
+    /// rolling over the month and day when they are out of the usual range.
+    ///
+    /// The month 13 is January of the next year and the month 0 is December of the last year.
+    /// The day is then counted from the first day of the resulting month,
+    /// so the day 32 of January is February 1 and the day 0 is the last day of the last month.
+    ///
+    /// Returns `None` only on the out-of-range date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_ymd_lenient = NaiveDate::from_ymd_lenient;
+    ///
+    /// assert_eq!(from_ymd_lenient(2015, 13, 1), Some(NaiveDate::from_ymd(2016, 1, 1)));
+    /// assert_eq!(from_ymd_lenient(2015, 1, 32), Some(NaiveDate::from_ymd(2015, 2, 1)));
+    /// assert_eq!(from_ymd_lenient(2015, 3, 0), Some(NaiveDate::from_ymd(2015, 2, 28)));
+    /// assert_eq!(from_ymd_lenient(2015, -1, 1), Some(NaiveDate::from_ymd(2014, 11, 1)));
+    /// assert_eq!(from_ymd_lenient(2015, 2, 31), Some(NaiveDate::from_ymd(2015, 3, 3)));
+    /// assert_eq!(from_ymd_lenient(400000, 1, 1), None);
+    /// ~~~~
+    pub fn from_ymd_lenient(year: i32, month: i32, day: i32) -> Option<NaiveDate> {
+        let (year_delta, month0) = div_mod_floor(i64::from(month) - 1, 12);
+        let year = try_opt!((i64::from(year) + year_delta).to_i32());
+        let first = try_opt!(NaiveDate::from_ymd_opt(year, month0 as u32 + 1, 1));
+        first.checked_add_signed(OldDuration::days(i64::from(day) - 1))
+    }
+
+    /// Makes a new `NaiveDate` from the `(year, month, day)` tuple,
+    /// as returned by [`to_ymd`](#method.to_ymd).
+    ///
+    /// Returns `None` on the out-of-range date, invalid month and/or day.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_tuple((2015, 3, 14)), Some(NaiveDate::from_ymd(2015, 3, 14)));
+    /// assert_eq!(NaiveDate::from_ymd_tuple((2015, 2, 29)), None);
+    /// ~~~~
+    #[inline]
+    pub fn from_ymd_tuple((year, month, day): (i32, u32, u32)) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    /// Returns the year, month and day of the [calendar date](#calendar-date) as a tuple.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 3, 14);
+    /// assert_eq!(d.to_ymd(), (2015, 3, 14));
+    /// assert_eq!(NaiveDate::from_ymd_tuple(d.to_ymd()), Some(d));
+    /// ~~~~
+    #[inline]
+    pub fn to_ymd(&self) -> (i32, u32, u32) {
+        let mdf = self.mdf();
+        (self.year(), mdf.month(), mdf.day())
+    }
+
     /// Makes a new `NaiveDate` from the [ordinal date](#ordinal-date)
     /// (year and day of the year).
     ///
@@ -235,6 +312,30 @@ impl NaiveDate {
         NaiveDate::from_of(year, Of::new(ordinal, flags))
     }
 
+    /// Converts the day of the year (starting from 1) to the month and day in given year,
+    /// without making a `NaiveDate`.
+    /// Unlike [`from_yo_opt`](#method.from_yo_opt), the year itself can be out of range.
+    ///
+    /// Returns `None` on the invalid day of the year.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::ordinal_to_monthday(2015, 60), Some((3, 1)));
+    /// assert_eq!(NaiveDate::ordinal_to_monthday(2016, 60), Some((2, 29)));
+    /// assert_eq!(NaiveDate::ordinal_to_monthday(2016, 366), Some((12, 31)));
+    /// assert_eq!(NaiveDate::ordinal_to_monthday(2015, 366), None);
+    /// assert_eq!(NaiveDate::ordinal_to_monthday(2015, 0), None);
+    /// ~~~~
+    pub fn ordinal_to_monthday(year: i32, ordinal: u32) -> Option<(u32, u32)> {
+        let of = Of::new(ordinal, YearFlags::from_year(year));
+        if !of.valid() { return None; }
+        let mdf = of.to_mdf();
+        Some((mdf.month(), mdf.day()))
+    }
+
     /// Makes a new `NaiveDate` from the [ISO week date](#week-date)
     /// (year, week number and day of the week).
     /// The resulting `NaiveDate` may have a different year from the input year.
@@ -407,6 +508,116 @@ impl NaiveDate {
                            Of::new(ordinal, flags))
     }
 
+    /// Makes a new `NaiveDate` from the Modified Julian Day (MJD) number,
+    /// where 0 is November 17, 1858.
+    ///
+    /// The fractional part, which denotes the time of the day, is discarded
+    /// (i.e. the number is rounded towards the negative infinity).
+    /// Use [`NaiveDateTime::from_mjd_f64`](./struct.NaiveDateTime.html#method.from_mjd_f64)
+    /// to keep the time.
+    ///
+    /// Returns `None` on the out-of-range date and non-finite numbers.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_mjd(51_544.0), Some(NaiveDate::from_ymd(2000, 1, 1)));
+    /// assert_eq!(NaiveDate::from_mjd(51_544.75), Some(NaiveDate::from_ymd(2000, 1, 1)));
+    /// assert_eq!(NaiveDate::from_mjd(-0.5), Some(NaiveDate::from_ymd(1858, 11, 16)));
+    /// assert_eq!(NaiveDate::from_mjd(1e12), None);
+    /// ~~~~
+    pub fn from_mjd(mjd: f64) -> Option<NaiveDate> {
+        let days = mjd.floor();
+        if !(f64::from(i32::MIN) <= days && days <= f64::from(i32::MAX)) {
+            return None; // also catches NaN
+        }
+        let days = try_opt!((days as i32).checked_add(MJD_EPOCH_DAYS_FROM_CE));
+        NaiveDate::from_num_days_from_ce_opt(days)
+    }
+
+    /// Returns the Modified Julian Day (MJD) number of the date at midnight,
+    /// where 0 is November 17, 1858.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2000, 1, 1).to_mjd(), 51_544.0);
+    /// assert_eq!(NaiveDate::from_ymd(1858, 11, 17).to_mjd(), 0.0);
+    /// ~~~~
+    #[inline]
+    pub fn to_mjd(&self) -> f64 {
+        f64::from(self.num_days_from_ce() - MJD_EPOCH_DAYS_FROM_CE)
+    }
+
+    /// Makes a new `NaiveDate` from the serial number of Microsoft Excel
+    /// (in its default 1900 date system), where 1 is January 1, 1900.
+    ///
+    /// Excel inherits the Lotus 1-2-3 bug that treats 1900 as a leap year,
+    /// so the serial 60 denotes the nonexistent February 29, 1900.
+    /// It is mapped to February 28, 1900 here, and later serials are shifted by one day.
+    ///
+    /// Returns `None` for the serial 0 and after December 31, 9999.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_serial = NaiveDate::from_excel_serial;
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(from_serial(1),         Some(from_ymd(1900, 1, 1)));
+    /// assert_eq!(from_serial(59),        Some(from_ymd(1900, 2, 28)));
+    /// assert_eq!(from_serial(60),        Some(from_ymd(1900, 2, 28)));
+    /// assert_eq!(from_serial(61),        Some(from_ymd(1900, 3, 1)));
+    /// assert_eq!(from_serial(45_292),    Some(from_ymd(2024, 1, 1)));
+    /// assert_eq!(from_serial(0),         None);
+    /// assert_eq!(from_serial(2_958_466), None);
+    /// ~~~~
+    pub fn from_excel_serial(serial: u32) -> Option<NaiveDate> {
+        if serial == 0 || serial > EXCEL_MAX_SERIAL {
+            return None;
+        }
+        let days = if serial < 60 { serial } else if serial == 60 { 59 } else { serial - 1 };
+        NaiveDate::from_num_days_from_ce_opt(EXCEL_EPOCH_DAYS_FROM_CE + days as i32)
+    }
+
+    /// Returns the serial number of Microsoft Excel (in its default 1900 date system)
+    /// for this date, where 1 is January 1, 1900.
+    ///
+    /// Dates from March 1, 1900 are shifted by one day
+    /// to account for the nonexistent February 29, 1900 in Excel.
+    /// See [`from_excel_serial`](#method.from_excel_serial) for the details.
+    ///
+    /// Returns `None` before January 1, 1900 and after December 31, 9999.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(1900, 1, 1).to_excel_serial(),   Some(1));
+    /// assert_eq!(NaiveDate::from_ymd(1900, 2, 28).to_excel_serial(),  Some(59));
+    /// assert_eq!(NaiveDate::from_ymd(1900, 3, 1).to_excel_serial(),   Some(61));
+    /// assert_eq!(NaiveDate::from_ymd(2024, 1, 1).to_excel_serial(),   Some(45_292));
+    /// assert_eq!(NaiveDate::from_ymd(1899, 12, 31).to_excel_serial(), None);
+    /// assert_eq!(NaiveDate::from_ymd(10000, 1, 1).to_excel_serial(),  None);
+    /// ~~~~
+    pub fn to_excel_serial(&self) -> Option<u32> {
+        let days = self.num_days_from_ce() - EXCEL_EPOCH_DAYS_FROM_CE;
+        if days < 1 || self.year() > 9999 {
+            None
+        } else if days < 60 {
+            Some(days as u32)
+        } else {
+            Some(days as u32 + 1)
+        }
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDate`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -455,6 +666,132 @@ impl NaiveDate {
         parsed.to_naive_date()
     }
 
+    /// Parses a string in any of the common date formats,
+    /// with the default [`DateParsePreferences`](./struct.DateParsePreferences.html).
+    /// See [`from_str_any_with`](#method.from_str_any_with) for the details.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(NaiveDate::from_str_any("2015-09-05"), Ok(d));
+    /// assert_eq!(NaiveDate::from_str_any("09/05/2015"), Ok(d));
+    /// assert_eq!(NaiveDate::from_str_any("5 Sep 2015"), Ok(d));
+    /// assert_eq!(NaiveDate::from_str_any("September 5, 2015"), Ok(d));
+    /// assert!(NaiveDate::from_str_any("next Saturday").is_err());
+    /// ~~~~
+    #[inline]
+    pub fn from_str_any(s: &str) -> ParseResult<NaiveDate> {
+        NaiveDate::from_str_any_with(s, &DateParsePreferences::default())
+    }
+
+    /// Parses a string in any of the common date formats,
+    /// returning the result of the first format that matches.
+    ///
+    /// The formats are tried in the following order:
+    ///
+    /// 1. The year first: `2015-09-05`, `2015/09/05`, `2015.09.05` and `20150905`.
+    /// 2. The numeric day and month, separated by `/`, `-` or `.`:
+    ///    `05/09/2015` (the day first) and `09/05/2015` (the month first).
+    ///    As they are ambiguous, the order in `preferences` is tried first
+    ///    and the other order is used only when the preferred one is invalid.
+    /// 3. The month name (either abbreviated or full), optionally after the day of the week:
+    ///    `05-Sep-2015`, `5 September 2015`, `Sep 5 2015`, `September 5, 2015`,
+    ///    `Sat, 5 Sep 2015` and `Saturday, September 5, 2015`.
+    ///
+    /// The year should be written in full, as the year `15` is read as the year 15.
+    ///
+    /// When no format matches, the error from the first format
+    /// that matched the input but gave an invalid date is returned,
+    /// or the error from the first format otherwise.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::naive::DateParsePreferences;
+    ///
+    /// let mut dmy = DateParsePreferences::new();
+    /// dmy.prefer_dmy = true;
+    /// let mdy = DateParsePreferences::new();
+    ///
+    /// // ambiguous
+    /// assert_eq!(NaiveDate::from_str_any_with("01/02/2024", &dmy),
+    ///            Ok(NaiveDate::from_ymd(2024, 2, 1)));
+    /// assert_eq!(NaiveDate::from_str_any_with("01/02/2024", &mdy),
+    ///            Ok(NaiveDate::from_ymd(2024, 1, 2)));
+    ///
+    /// // not ambiguous
+    /// assert_eq!(NaiveDate::from_str_any_with("13/02/2024", &mdy),
+    ///            Ok(NaiveDate::from_ymd(2024, 2, 13)));
+    /// ~~~~
+    pub fn from_str_any_with(s: &str, preferences: &DateParsePreferences)
+                             -> ParseResult<NaiveDate> {
+        static YEAR_FIRST: [&'static str; 4] = ["%Y-%m-%d", "%Y/%m/%d", "%Y.%m.%d", "%Y%m%d"];
+        static DAY_FIRST: [&'static str; 3] = ["%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y"];
+        static MONTH_FIRST: [&'static str; 3] = ["%m/%d/%Y", "%m-%d-%Y", "%m.%d.%Y"];
+        static MONTH_NAME: [&'static str; 7] = ["%d-%B-%Y", "%d %B %Y", "%B %d %Y", "%B %d, %Y",
+                                                "%A, %d %B %Y", "%A %d %B %Y", "%A, %B %d, %Y"];
+
+        let (numeric1, numeric2) = if preferences.prefer_dmy {
+            (&DAY_FIRST, &MONTH_FIRST)
+        } else {
+            (&MONTH_FIRST, &DAY_FIRST)
+        };
+        let formats = YEAR_FIRST.iter().chain(numeric1.iter()).chain(numeric2.iter())
+                                .chain(MONTH_NAME.iter());
+
+        let mut first_err = None;
+        let mut invalid_date_err = None;
+        for fmt in formats {
+            let mut parsed = Parsed::new();
+            match parse(&mut parsed, s, StrftimeItems::new(fmt)) {
+                Ok(()) => match parsed.to_naive_date() {
+                    Ok(date) => return Ok(date),
+                    Err(e) => { invalid_date_err = invalid_date_err.or(Some(e)); }
+                },
+                Err(e) => { first_err = first_err.or(Some(e)); }
+            }
+        }
+        Err(invalid_date_err.or(first_err).unwrap())
+    }
+
+    /// Parses a date in the `DD.MM.YYYY` format common in Europe, e.g. `01.03.2024`.
+    /// The day and month can be also written without zero-padding, e.g. `1.3.2024`.
+    ///
+    /// This is same to `NaiveDate::parse_from_str(s, "%d.%m.%Y")`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_dmy_str("01.03.2024"), Ok(NaiveDate::from_ymd(2024, 3, 1)));
+    /// assert_eq!(NaiveDate::from_dmy_str("1.3.2024"), Ok(NaiveDate::from_ymd(2024, 3, 1)));
+    /// assert!(NaiveDate::from_dmy_str("31.04.2024").is_err());
+    /// assert!(NaiveDate::from_dmy_str("2024-03-01").is_err());
+    /// ~~~~
+    pub fn from_dmy_str(s: &str) -> ParseResult<NaiveDate> {
+        NaiveDate::parse_from_str(s, DMY_FORMAT)
+    }
+
+    /// Formats the date in the `DD.MM.YYYY` format common in Europe, e.g. `01.03.2024`.
+    ///
+    /// This is same to `self.format("%d.%m.%Y").to_string()`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2024, 3, 1).format_dmy(), "01.03.2024");
+    /// ~~~~
+    pub fn format_dmy(&self) -> String {
+        self.format(DMY_FORMAT).to_string()
+    }
+
     /// Makes a new `NaiveDateTime` from the current date and given `NaiveTime`.
     ///
     /// # Example
@@ -781,6 +1118,122 @@ impl NaiveDate {
         self.with_of(self.of().pred()).or_else(|| NaiveDate::from_ymd_opt(self.year() - 1, 12, 31))
     }
 
+    /// Makes a new `NaiveDate` with the [ISO week](#week-date) number changed,
+    /// keeping the ISO week year and the day of the week.
+    ///
+    /// Returns `None` when the week number is invalid for that ISO week year
+    /// or the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 9, 8); // Tuesday of 2015-W37
+    /// assert_eq!(d.with_iso_week(1), Some(NaiveDate::from_ymd(2014, 12, 30)));
+    /// assert_eq!(d.with_iso_week(53), Some(NaiveDate::from_ymd(2015, 12, 29)));
+    /// assert_eq!(d.with_iso_week(54), None);
+    /// assert_eq!(NaiveDate::from_ymd(2016, 9, 8).with_iso_week(53), None);
+    /// ~~~~
+    pub fn with_iso_week(&self, week: u32) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(self.iso_week().year(), week, self.weekday())
+    }
+
+    /// Makes a new `NaiveDate` for the given day of the week
+    /// within the same [ISO week](#week-date) (Monday through Sunday).
+    ///
+    /// Panics when the resulting date would be out of range,
+    /// which can only happen in the first or last week of the representable range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 9, 9); // Wednesday
+    /// assert_eq!(d.with_weekday(Weekday::Mon), NaiveDate::from_ymd(2015, 9, 7));
+    /// assert_eq!(d.with_weekday(Weekday::Wed), d);
+    /// assert_eq!(d.with_weekday(Weekday::Sun), NaiveDate::from_ymd(2015, 9, 13));
+    /// ~~~~
+    pub fn with_weekday(&self, weekday: Weekday) -> NaiveDate {
+        let delta = i64::from(weekday.num_days_from_monday()) -
+                    i64::from(self.weekday().num_days_from_monday());
+        self.checked_add_signed(OldDuration::days(delta)).expect("out of bound")
+    }
+
+    /// Advances the date by `n` business days (Monday through Friday),
+    /// or goes back by `-n` business days when `n` is negative.
+    ///
+    /// A date on the weekend is first moved to the adjacent business day
+    /// in the opposite direction, so Saturday plus one business day is Monday
+    /// and Sunday minus one business day is Friday.
+    /// When `n` is zero, a weekend date is moved to the following Monday
+    /// and a weekday is returned unchanged.
+    ///
+    /// Panics when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let fri = NaiveDate::from_ymd(2015, 9, 4);
+    /// let sat = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(fri.business_day_add(1), NaiveDate::from_ymd(2015, 9, 7));
+    /// assert_eq!(fri.business_day_add(5), NaiveDate::from_ymd(2015, 9, 11));
+    /// assert_eq!(fri.business_day_add(-4), NaiveDate::from_ymd(2015, 8, 31));
+    /// assert_eq!(sat.business_day_add(1), NaiveDate::from_ymd(2015, 9, 7));
+    /// assert_eq!(sat.business_day_add(-1), fri);
+    /// assert_eq!(sat.business_day_add(0), NaiveDate::from_ymd(2015, 9, 7));
+    /// ~~~~
+    pub fn business_day_add(&self, n: i32) -> NaiveDate {
+        let weekday = i64::from(self.weekday().num_days_from_monday());
+        let n = i64::from(n);
+
+        // start from a weekday (0 = Monday, ..., 4 = Friday) so that the steps are uniform
+        let (start, weekday) = match (weekday < 5, n > 0) {
+            (true, _) => (0, weekday),
+            (false, true) => (4 - weekday, 4), // back to Friday
+            (false, false) => (7 - weekday, 0), // forward to Monday
+        };
+
+        let (weeks, rem) = (n / 5, n % 5);
+        let mut days = start + weeks * 7 + rem;
+        if weekday + rem > 4 {
+            days += 2;
+        } else if weekday + rem < 0 {
+            days -= 2;
+        }
+        self.checked_add_signed(OldDuration::days(days)).expect("out of bound")
+    }
+
+    /// Counts the dates falling on given weekday from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// Returns 0 when `end` is not later than `start`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let start = NaiveDate::from_ymd(2015, 9, 1); // Tuesday
+    /// let end = NaiveDate::from_ymd(2015, 10, 1);
+    /// assert_eq!(NaiveDate::count_weekdays_between(start, end, Weekday::Mon), 4);
+    /// assert_eq!(NaiveDate::count_weekdays_between(start, end, Weekday::Tue), 5);
+    /// assert_eq!(NaiveDate::count_weekdays_between(end, start, Weekday::Tue), 0);
+    /// ~~~~
+    pub fn count_weekdays_between(start: NaiveDate, end: NaiveDate, weekday: Weekday) -> i64 {
+        let days = end.signed_duration_since(start).num_days();
+        // the number of days from `start` to the first date on `weekday`
+        let first = i64::from((7 + weekday.num_days_from_monday() -
+                               start.weekday().num_days_from_monday()) % 7);
+        if days <= first {
+            0
+        } else {
+            (days - first + 6) / 7
+        }
+    }
+
     /// Adds the `days` part of given `Duration` to the current date.
     ///
     /// Returns `None` when it will result in overflow.
@@ -889,6 +1342,35 @@ impl NaiveDate {
                           (cycle1 - cycle2))
     }
 
+    /// Restricts the value to the inclusive range from `min` to `max`.
+    ///
+    /// Returns `min` if `self` is earlier than `min`, `max` if `self` is later than `max`,
+    /// and `self` otherwise.
+    ///
+    /// Panics if `min` is later than `max`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let (min, max) = (NaiveDate::from_ymd(2015, 9, 1), NaiveDate::from_ymd(2015, 9, 30));
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(d.clamp(min, max), d);
+    /// assert_eq!(NaiveDate::from_ymd(2015, 10, 5).clamp(min, max), max);
+    /// assert_eq!(NaiveDate::from_ymd(2015, 8, 5).clamp(min, max), min);
+    /// ~~~~
+    pub fn clamp(self, min: NaiveDate, max: NaiveDate) -> NaiveDate {
+        assert!(min <= max, "`min` should not be later than `max`");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
     /// Formats the date with the specified formatting items.
     /// Otherwise it is same to the ordinary `format` method.
     ///
@@ -917,8 +1399,8 @@ impl NaiveDate {
     /// assert_eq!(format!("{}", d.format_with_items(fmt)), "2015-09-05");
     /// ~~~~
     #[inline]
-    pub fn format_with_items<'a, I>(&self, items: I) -> DelayedFormat<I>
-            where I: Iterator<Item=Item<'a>> + Clone {
+    pub fn format_with_items<'a, I, B>(&self, items: I) -> DelayedFormat<I>
+            where I: Iterator<Item=B> + Clone, B: Borrow<Item<'a>> {
         DelayedFormat::new(Some(*self), None, items)
     }
 
@@ -958,6 +1440,169 @@ impl NaiveDate {
     pub fn format<'a>(&self, fmt: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
         self.format_with_items(StrftimeItems::new(fmt))
     }
+
+    /// Returns the English name of the month, e.g. `"September"`.
+    /// This is same to the output of `%B` format specifier.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).month_name(), "September");
+    /// ~~~~
+    #[inline]
+    pub fn month_name(&self) -> &'static str {
+        LONG_MONTHS[self.month0() as usize]
+    }
+
+    /// Returns the abbreviated English name of the month, e.g. `"Sep"`.
+    /// This is same to the output of `%b` format specifier.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).month_name_abbr(), "Sep");
+    /// ~~~~
+    #[inline]
+    pub fn month_name_abbr(&self) -> &'static str {
+        SHORT_MONTHS[self.month0() as usize]
+    }
+
+    /// Returns the English name of the day of the week, e.g. `"Saturday"`.
+    /// This is same to the output of `%A` format specifier.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).weekday_name(), "Saturday");
+    /// ~~~~
+    #[inline]
+    pub fn weekday_name(&self) -> &'static str {
+        LONG_WEEKDAYS[self.weekday().num_days_from_monday() as usize]
+    }
+
+    /// Returns the abbreviated English name of the day of the week, e.g. `"Sat"`.
+    /// This is same to the output of `%a` format specifier.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).weekday_name_abbr(), "Sat");
+    /// ~~~~
+    #[inline]
+    pub fn weekday_name_abbr(&self) -> &'static str {
+        SHORT_WEEKDAYS[self.weekday().num_days_from_monday() as usize]
+    }
+
+    /// Returns the ISO 8601 week number starting from 1.
+    /// This is same to the output of `%V` format specifier.
+    ///
+    /// The week may belong to the last or next ISO week-numbering year,
+    /// see [`iso_week`](../trait.Datelike.html#tymethod.iso_week) for the year.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).week_of_year_iso(), 36);
+    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).week_of_year_iso(), 53); // of 2015
+    /// ~~~~
+    #[inline]
+    pub fn week_of_year_iso(&self) -> u32 {
+        self.iso_week().week()
+    }
+
+    /// Returns the week number starting from 0, where the week 1 starts
+    /// from the first Sunday of the year. Days before that are in the week 0.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).week_of_year_us(), 35);
+    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 2).week_of_year_us(), 0); // Saturday
+    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 3).week_of_year_us(), 1); // Sunday
+    /// ~~~~
+    #[inline]
+    pub fn week_of_year_us(&self) -> u32 {
+        (self.ordinal() + 6 - self.weekday().num_days_from_sunday()) / 7
+    }
+
+    /// Returns the first day of the month containing this date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).first_day_of_month(),
+    ///            NaiveDate::from_ymd(2015, 9, 1));
+    /// ~~~~
+    #[inline]
+    pub fn first_day_of_month(&self) -> NaiveDate {
+        self.with_day(1).unwrap()
+    }
+
+    /// Returns the last day of the month containing this date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).last_day_of_month(),
+    ///            NaiveDate::from_ymd(2015, 9, 30));
+    /// assert_eq!(NaiveDate::from_ymd(2016, 2, 5).last_day_of_month(),
+    ///            NaiveDate::from_ymd(2016, 2, 29));
+    /// ~~~~
+    pub fn last_day_of_month(&self) -> NaiveDate {
+        let ndays = match self.month() {
+            2 => if self.of().flags().ndays() == 366 { 29 } else { 28 },
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        self.with_day(ndays).unwrap()
+    }
+
+    /// Returns January 1 of the year containing this date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).first_day_of_year(),
+    ///            NaiveDate::from_ymd(2015, 1, 1));
+    /// ~~~~
+    #[inline]
+    pub fn first_day_of_year(&self) -> NaiveDate {
+        self.with_ordinal(1).unwrap()
+    }
+
+    /// Returns December 31 of the year containing this date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).last_day_of_year(),
+    ///            NaiveDate::from_ymd(2015, 12, 31));
+    /// assert_eq!(NaiveDate::from_ymd(2016, 9, 5).last_day_of_year().ordinal(), 366);
+    /// ~~~~
+    #[inline]
+    pub fn last_day_of_year(&self) -> NaiveDate {
+        self.with_ordinal(self.of().flags().ndays()).unwrap()
+    }
 }
 
 impl Datelike for NaiveDate {
@@ -1421,6 +2066,43 @@ impl Sub<NaiveDate> for NaiveDate {
     }
 }
 
+/// Preferences for parsing dates in various formats with
+/// [`NaiveDate::from_str_any_with`](./struct.NaiveDate.html#method.from_str_any_with).
+///
+/// More preferences may be added in the future,
+/// so the value should be made from the default value and then changed:
+///
+/// ~~~~
+/// use chrono::naive::DateParsePreferences;
+///
+/// let mut preferences = DateParsePreferences::new();
+/// preferences.prefer_dmy = true;
+/// ~~~~
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateParsePreferences {
+    /// If true, the ambiguous numeric date like `01/02/2024` is read as
+    /// the day followed by the month (February 1).
+    /// Otherwise it is read as the month followed by the day (January 2).
+    /// Defaults to false.
+    pub prefer_dmy: bool,
+
+    /// A dummy field to make this type not fully destructible (required for API stability).
+    _dummy: (),
+}
+
+impl DateParsePreferences {
+    /// Returns the default preferences.
+    pub fn new() -> DateParsePreferences {
+        DateParsePreferences { prefer_dmy: false, _dummy: () }
+    }
+}
+
+impl Default for DateParsePreferences {
+    fn default() -> DateParsePreferences {
+        DateParsePreferences::new()
+    }
+}
+
 /// The `Debug` output of the naive date `d` is same to
 /// [`d.format("%Y-%m-%d")`](../format/strftime/index.html).
 ///
@@ -1700,6 +2382,42 @@ mod tests {
         assert!(ymd_opt(2014, 13, 1).is_none());
     }
 
+    #[test]
+    fn test_date_from_ymd_lenient() {
+        let lenient = NaiveDate::from_ymd_lenient;
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+
+        // valid dates are unchanged
+        let mut d = ymd(2015, 1, 1);
+        while d.year() < 2017 {
+            assert_eq!(lenient(d.year(), d.month() as i32, d.day() as i32), Some(d));
+            d = d.succ();
+        }
+
+        assert_eq!(lenient(2015, 13, 1), Some(ymd(2016, 1, 1)));
+        assert_eq!(lenient(2015, 25, 1), Some(ymd(2017, 1, 1)));
+        assert_eq!(lenient(2015, 0, 1), Some(ymd(2014, 12, 1)));
+        assert_eq!(lenient(2015, -11, 1), Some(ymd(2014, 1, 1)));
+        assert_eq!(lenient(2015, -12, 1), Some(ymd(2013, 12, 1)));
+        assert_eq!(lenient(2015, 1, 32), Some(ymd(2015, 2, 1)));
+        assert_eq!(lenient(2016, 2, 30), Some(ymd(2016, 3, 1)));
+        assert_eq!(lenient(2015, 1, 0), Some(ymd(2014, 12, 31)));
+        assert_eq!(lenient(2015, 1, -30), Some(ymd(2014, 12, 1)));
+        assert_eq!(lenient(2015, 12, 366), Some(ymd(2016, 11, 30)));
+        assert_eq!(lenient(2015, 14, 29), Some(ymd(2016, 2, 29)));
+        assert_eq!(lenient(2015, 14, 30), Some(ymd(2016, 3, 1)));
+
+        assert_eq!(lenient(MAX_YEAR, 12, 31), Some(MAX_DATE));
+        assert_eq!(lenient(MAX_YEAR, 12, 32), None);
+        assert_eq!(lenient(MAX_YEAR, 13, 1), None);
+        assert_eq!(lenient(MIN_YEAR, 1, 1), Some(MIN_DATE));
+        assert_eq!(lenient(MIN_YEAR, 1, 0), None);
+        assert_eq!(lenient(i32::MAX, i32::MAX, i32::MAX), None);
+        assert_eq!(lenient(i32::MIN, i32::MIN, i32::MIN), None);
+        assert_eq!(lenient(2015, i32::MAX, 1), None);
+        assert_eq!(lenient(2015, 1, i32::MIN), None);
+    }
+
     #[test]
     fn test_date_from_yo() {
         let yo_opt = |y,o| NaiveDate::from_yo_opt(y, o);
@@ -1730,6 +2448,37 @@ mod tests {
         assert_eq!(yo_opt(2014, 366), None);
     }
 
+    #[test]
+    fn test_date_ordinal_to_monthday() {
+        for &year in &[2015, 2016, 2000, 1900, -4, i32::MAX, i32::MIN] {
+            let ndays = if NaiveDate::ordinal_to_monthday(year, 366).is_some() { 366 } else { 365 };
+            let mut expected = (1, 1);
+            for ordinal in 1..ndays + 1 {
+                assert_eq!(NaiveDate::ordinal_to_monthday(year, ordinal), Some(expected));
+                if let Some(d) = NaiveDate::from_yo_opt(year, ordinal) {
+                    assert_eq!((d.month(), d.day()), expected);
+                }
+                // advance in 2000 or 1999, which has the same number of days
+                expected = match NaiveDate::from_ymd_opt(2000 + (ndays as i32 - 366),
+                                                         expected.0, expected.1 + 1) {
+                    Some(_) => (expected.0, expected.1 + 1),
+                    None => (expected.0 + 1, 1),
+                };
+            }
+            assert_eq!(expected, (13, 1));
+            assert_eq!(NaiveDate::ordinal_to_monthday(year, ndays + 1), None);
+            assert_eq!(NaiveDate::ordinal_to_monthday(year, 0), None);
+        }
+
+        // the boundary between February and March
+        assert_eq!(NaiveDate::ordinal_to_monthday(2015, 59), Some((2, 28)));
+        assert_eq!(NaiveDate::ordinal_to_monthday(2015, 60), Some((3, 1)));
+        assert_eq!(NaiveDate::ordinal_to_monthday(2016, 59), Some((2, 28)));
+        assert_eq!(NaiveDate::ordinal_to_monthday(2016, 60), Some((2, 29)));
+        assert_eq!(NaiveDate::ordinal_to_monthday(2016, 61), Some((3, 1)));
+        assert_eq!(NaiveDate::ordinal_to_monthday(2016, u32::MAX), None);
+    }
+
     #[test]
     fn test_date_from_isoywd() {
         let isoywd_opt = |y,w,d| NaiveDate::from_isoywd_opt(y, w, d);
@@ -1909,6 +2658,403 @@ mod tests {
         assert_eq!(d.with_ordinal(u32::MAX), None);
     }
 
+    #[test]
+    fn test_date_with_iso_week() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+
+        // 2015 has 53 ISO weeks, 2016 has 52
+        let d = ymd(2015, 12, 31); // Thursday of 2015-W53
+        assert_eq!(d.with_iso_week(0), None);
+        assert_eq!(d.with_iso_week(1), Some(ymd(2015, 1, 1)));
+        assert_eq!(d.with_iso_week(52), Some(ymd(2015, 12, 24)));
+        assert_eq!(d.with_iso_week(53), Some(d));
+        assert_eq!(d.with_iso_week(54), None);
+
+        // the ISO week year is kept even if it differs from the calendar year
+        let d = ymd(2016, 1, 3); // Sunday of 2015-W53
+        assert_eq!(d.with_iso_week(53), Some(d));
+        assert_eq!(d.with_iso_week(52), Some(ymd(2015, 12, 27)));
+        assert_eq!(d.with_iso_week(1), Some(ymd(2015, 1, 4)));
+
+        let d = ymd(2016, 1, 4); // Monday of 2016-W01, right after 2015-W53
+        assert_eq!(d.with_iso_week(1), Some(d));
+        assert_eq!(d.with_iso_week(52), Some(ymd(2016, 12, 26)));
+        assert_eq!(d.with_iso_week(53), None);
+
+        let d = ymd(2014, 12, 29); // Monday of 2015-W01
+        assert_eq!(d.with_iso_week(53), Some(ymd(2015, 12, 28)));
+    }
+
+    #[test]
+    fn test_date_with_weekday() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        let weekdays = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu,
+                        Weekday::Fri, Weekday::Sat, Weekday::Sun];
+
+        for (i, &wd) in weekdays.iter().enumerate() {
+            // week 2015-W53 spans the year boundary
+            let expected = ymd(2015, 12, 28) + Duration::days(i as i64);
+            for day in 28..35 {
+                let d = ymd(2015, 12, 28) + Duration::days(day - 28);
+                let d_ = d.with_weekday(wd);
+                assert_eq!(d_, expected);
+                assert_eq!(d_.weekday(), wd);
+                assert_eq!(d_.iso_week().year(), d.iso_week().year());
+                assert_eq!(d_.iso_week().week(), d.iso_week().week());
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_names() {
+        // the accessors should always agree with the corresponding format specifiers
+        let mut d = NaiveDate::from_ymd(2016, 1, 1);
+        while d.year() == 2016 {
+            assert_eq!(d.month_name(), d.format("%B").to_string());
+            assert_eq!(d.month_name_abbr(), d.format("%b").to_string());
+            assert_eq!(d.weekday_name(), d.format("%A").to_string());
+            assert_eq!(d.weekday_name_abbr(), d.format("%a").to_string());
+            d = d.succ();
+        }
+
+        let d = NaiveDate::from_ymd(2017, 2, 27);
+        assert_eq!((d.month_name(), d.month_name_abbr()), ("February", "Feb"));
+        assert_eq!((d.weekday_name(), d.weekday_name_abbr()), ("Monday", "Mon"));
+    }
+
+    #[test]
+    fn test_date_week_of_year() {
+        // (year, January 1 falls on, ISO week of January 1, US week of January 1 to 7)
+        let cases = [
+            (2018, Weekday::Mon, 1, [0, 0, 0, 0, 0, 0, 1]),
+            (2019, Weekday::Tue, 1, [0, 0, 0, 0, 0, 1, 1]),
+            (2020, Weekday::Wed, 1, [0, 0, 0, 0, 1, 1, 1]),
+            (2015, Weekday::Thu, 1, [0, 0, 0, 1, 1, 1, 1]),
+            (2016, Weekday::Fri, 53, [0, 0, 1, 1, 1, 1, 1]),
+            (2022, Weekday::Sat, 52, [0, 1, 1, 1, 1, 1, 1]),
+            (2017, Weekday::Sun, 52, [1, 1, 1, 1, 1, 1, 1]),
+        ];
+        for &(year, weekday, iso_week, us_weeks) in &cases {
+            let jan1 = NaiveDate::from_ymd(year, 1, 1);
+            assert_eq!(jan1.weekday(), weekday);
+            assert_eq!(jan1.week_of_year_iso(), iso_week, "{:?}", jan1);
+            for (day, &us_week) in us_weeks.iter().enumerate() {
+                let d = NaiveDate::from_ymd(year, 1, day as u32 + 1);
+                assert_eq!(d.week_of_year_us(), us_week, "{:?}", d);
+            }
+
+            // the ISO week number should always agree with the format specifier
+            let mut d = jan1;
+            while d.year() == year {
+                assert_eq!(d.week_of_year_iso().to_string(), d.format("%-V").to_string());
+                d = d.succ();
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_first_last_day_of_month() {
+        let ndays = [(2015, [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]),
+                     (2016, [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31])];
+        for &(year, ref ndays) in &ndays {
+            for (month0, &ndays) in ndays.iter().enumerate() {
+                let month = month0 as u32 + 1;
+                let first = NaiveDate::from_ymd(year, month, 1);
+                let last = NaiveDate::from_ymd(year, month, ndays);
+                for day in 1..ndays + 1 {
+                    let d = NaiveDate::from_ymd(year, month, day);
+                    assert_eq!(d.first_day_of_month(), first);
+                    assert_eq!(d.last_day_of_month(), last);
+                }
+                assert_eq!(last.succ().day(), 1);
+            }
+        }
+
+        assert_eq!(NaiveDate::from_ymd(1900, 2, 10).last_day_of_month(),
+                   NaiveDate::from_ymd(1900, 2, 28));
+        assert_eq!(NaiveDate::from_ymd(2000, 2, 10).last_day_of_month(),
+                   NaiveDate::from_ymd(2000, 2, 29));
+        assert_eq!(MIN_DATE.first_day_of_month(), MIN_DATE);
+        assert_eq!(MAX_DATE.last_day_of_month(), MAX_DATE);
+    }
+
+    #[test]
+    fn test_date_first_last_day_of_year() {
+        for &year in &[-400, -1, 0, 1, 1900, 2000, 2015, 2016] {
+            let first = NaiveDate::from_ymd(year, 1, 1);
+            let last = NaiveDate::from_ymd(year, 12, 31);
+            let mut d = first;
+            while d.year() == year {
+                assert_eq!(d.first_day_of_year(), first);
+                assert_eq!(d.last_day_of_year(), last);
+                d = d.succ();
+            }
+        }
+
+        assert_eq!(MIN_DATE.first_day_of_year(), MIN_DATE);
+        assert_eq!(MAX_DATE.last_day_of_year(), MAX_DATE);
+    }
+
+    #[test]
+    fn test_date_mjd() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(NaiveDate::from_mjd(0.0), Some(ymd(1858, 11, 17)));
+        assert_eq!(NaiveDate::from_mjd(51_544.0), Some(ymd(2000, 1, 1)));
+        assert_eq!(NaiveDate::from_mjd(51_544.999), Some(ymd(2000, 1, 1)));
+        assert_eq!(NaiveDate::from_mjd(51_545.0), Some(ymd(2000, 1, 2)));
+        assert_eq!(NaiveDate::from_mjd(-0.001), Some(ymd(1858, 11, 16)));
+        assert_eq!(NaiveDate::from_mjd(-678_576.0), Some(ymd(0, 12, 31)));
+
+        assert_eq!(ymd(1858, 11, 17).to_mjd(), 0.0);
+        assert_eq!(ymd(2000, 1, 1).to_mjd(), 51_544.0);
+        assert_eq!(ymd(1858, 11, 16).to_mjd(), -1.0);
+
+        for &d in &[MIN_DATE, MAX_DATE, ymd(1, 1, 1), ymd(2016, 2, 29)] {
+            assert_eq!(NaiveDate::from_mjd(d.to_mjd()), Some(d));
+        }
+        assert_eq!(NaiveDate::from_mjd(MAX_DATE.to_mjd() + 1.0), None);
+        assert_eq!(NaiveDate::from_mjd(MIN_DATE.to_mjd() - 1.0), None);
+        assert_eq!(NaiveDate::from_mjd(1e300), None);
+        assert_eq!(NaiveDate::from_mjd(::std::f64::NAN), None);
+        assert_eq!(NaiveDate::from_mjd(::std::f64::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn test_date_excel_serial() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+
+        assert_eq!(NaiveDate::from_excel_serial(1), Some(ymd(1900, 1, 1)));
+        assert_eq!(NaiveDate::from_excel_serial(59), Some(ymd(1900, 2, 28)));
+        assert_eq!(NaiveDate::from_excel_serial(60), Some(ymd(1900, 2, 28)));
+        assert_eq!(NaiveDate::from_excel_serial(61), Some(ymd(1900, 3, 1)));
+        assert_eq!(NaiveDate::from_excel_serial(45_292), Some(ymd(2024, 1, 1)));
+        assert_eq!(NaiveDate::from_excel_serial(2_958_465), Some(ymd(9999, 12, 31)));
+        assert_eq!(NaiveDate::from_excel_serial(0), None);
+        assert_eq!(NaiveDate::from_excel_serial(2_958_466), None);
+        assert_eq!(NaiveDate::from_excel_serial(u32::MAX), None);
+
+        assert_eq!(ymd(1900, 1, 1).to_excel_serial(), Some(1));
+        assert_eq!(ymd(1900, 2, 28).to_excel_serial(), Some(59));
+        assert_eq!(ymd(1900, 3, 1).to_excel_serial(), Some(61));
+        assert_eq!(ymd(2024, 1, 1).to_excel_serial(), Some(45_292));
+        assert_eq!(ymd(9999, 12, 31).to_excel_serial(), Some(2_958_465));
+        assert_eq!(ymd(1899, 12, 31).to_excel_serial(), None);
+        assert_eq!(ymd(10000, 1, 1).to_excel_serial(), None);
+        assert_eq!(MIN_DATE.to_excel_serial(), None);
+        assert_eq!(MAX_DATE.to_excel_serial(), None);
+
+        // every serial except the fictitious February 29, 1900 round-trips
+        for serial in (1..2_000).chain(2_958_000..2_958_466) {
+            let d = NaiveDate::from_excel_serial(serial).unwrap();
+            if serial != 60 {
+                assert_eq!(d.to_excel_serial(), Some(serial));
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_business_day_add() {
+        fn is_business_day(d: NaiveDate) -> bool {
+            d.weekday() != Weekday::Sat && d.weekday() != Weekday::Sun
+        }
+
+        // counts business days one by one, as the reference
+        fn naive_business_day_add(mut d: NaiveDate, n: i32) -> NaiveDate {
+            if n == 0 {
+                while !is_business_day(d) { d = d.succ(); }
+            }
+            for _ in 0..n.abs() {
+                d = if n > 0 { d.succ() } else { d.pred() };
+                while !is_business_day(d) {
+                    d = if n > 0 { d.succ() } else { d.pred() };
+                }
+            }
+            d
+        }
+
+        // 2015-09-07 is Monday
+        for day in 7..14 {
+            let d = NaiveDate::from_ymd(2015, 9, day);
+            for n in -25..26 {
+                let d_ = d.business_day_add(n);
+                assert_eq!(d_, naive_business_day_add(d, n), "{:?} + {}", d, n);
+                assert!(is_business_day(d_));
+            }
+            assert_eq!(d.business_day_add(100), naive_business_day_add(d, 100));
+            assert_eq!(d.business_day_add(-100), naive_business_day_add(d, -100));
+        }
+
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(ymd(2015, 9, 12).business_day_add(1), ymd(2015, 9, 14)); // Sat -> Mon
+        assert_eq!(ymd(2015, 9, 13).business_day_add(1), ymd(2015, 9, 14)); // Sun -> Mon
+        assert_eq!(ymd(2015, 9, 12).business_day_add(-1), ymd(2015, 9, 11)); // Sat -> Fri
+        assert_eq!(ymd(2015, 9, 13).business_day_add(0), ymd(2015, 9, 14)); // Sun -> Mon
+        assert_eq!(ymd(2015, 9, 7).business_day_add(100), ymd(2016, 1, 25)); // 20 weeks
+    }
+
+    #[test]
+    fn test_date_count_weekdays_between() {
+        let count = NaiveDate::count_weekdays_between;
+        let weekdays = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu,
+                        Weekday::Fri, Weekday::Sat, Weekday::Sun];
+
+        // 2015-09-07 is Monday
+        let mon = NaiveDate::from_ymd(2015, 9, 7);
+        for day in 7..14 {
+            let start = NaiveDate::from_ymd(2015, 9, day);
+            for &wd in &weekdays {
+                assert_eq!(count(start, start + Duration::days(7), wd), 1);
+                assert_eq!(count(start, start, wd), 0);
+                assert_eq!(count(start, start - Duration::days(10), wd), 0);
+
+                // compare with counting one by one
+                let mut expected = 0;
+                let mut end = start;
+                for _ in 0..30 {
+                    assert_eq!(count(start, end, wd), expected, "{:?}..{:?} {:?}", start, end, wd);
+                    if end.weekday() == wd { expected += 1; }
+                    end = end.succ();
+                }
+            }
+        }
+
+        // starting and ending on the target weekday
+        assert_eq!(count(mon, mon + Duration::weeks(3), Weekday::Mon), 3);
+        assert_eq!(count(mon, mon + Duration::weeks(3) + Duration::days(1), Weekday::Mon), 4);
+        assert_eq!(count(mon, mon.succ(), Weekday::Mon), 1);
+        assert_eq!(count(mon, mon.succ(), Weekday::Tue), 0);
+
+        // every date falls on exactly one weekday
+        let total: i64 = weekdays.iter().map(|&wd| count(MIN_DATE, MAX_DATE, wd)).sum();
+        assert_eq!(total, MAX_DATE.signed_duration_since(MIN_DATE).num_days());
+    }
+
+    #[test]
+    fn test_date_ymd_tuple() {
+        let mut d = NaiveDate::from_ymd(1900, 1, 1);
+        while d.year() < 2100 {
+            let (y, m, day) = d.to_ymd();
+            assert_eq!((y, m, day), (d.year(), d.month(), d.day()));
+            assert_eq!(NaiveDate::from_ymd_tuple((y, m, day)), Some(d));
+            d = d.succ();
+        }
+
+        for &d in &[MIN_DATE, MAX_DATE, NaiveDate::from_ymd(0, 2, 29)] {
+            assert_eq!(NaiveDate::from_ymd_tuple(d.to_ymd()), Some(d));
+        }
+        assert_eq!(NaiveDate::from_ymd_tuple((2015, 13, 1)), None);
+        assert_eq!(NaiveDate::from_ymd_tuple((2015, 4, 31)), None);
+        assert_eq!(NaiveDate::from_ymd_tuple((MAX_YEAR + 1, 1, 1)), None);
+    }
+
+    #[test]
+    fn test_date_clamp() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        let (min, max) = (ymd(2016, 2, 1), ymd(2016, 2, 29));
+        assert_eq!(ymd(2016, 2, 14).clamp(min, max), ymd(2016, 2, 14));
+        assert_eq!(ymd(2016, 1, 31).clamp(min, max), min);
+        assert_eq!(ymd(2016, 3, 1).clamp(min, max), max);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(MIN_DATE.clamp(min, min), min);
+        assert_eq!(MAX_DATE.clamp(MIN_DATE, MAX_DATE), MAX_DATE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_date_clamp_invalid_range() {
+        let d = NaiveDate::from_ymd(2016, 2, 14);
+        d.clamp(d.succ(), d.pred());
+    }
+
+    #[test]
+    fn test_date_dmy_str() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+
+        // the last day of each month
+        for month in 1..13 {
+            let d = NaiveDate::from_ymd_lenient(2023, month as i32 + 1, 0).unwrap();
+            assert_eq!(NaiveDate::from_dmy_str(&d.format_dmy()), Ok(d));
+            assert_eq!(d.format_dmy(), format!("{:02}.{:02}.2023", d.day(), month));
+        }
+
+        assert_eq!(NaiveDate::from_dmy_str("29.02.2024"), Ok(ymd(2024, 2, 29)));
+        assert_eq!(NaiveDate::from_dmy_str("29.2.2024"), Ok(ymd(2024, 2, 29)));
+        assert_eq!(NaiveDate::from_dmy_str("9.12.2024"), Ok(ymd(2024, 12, 9)));
+        assert_eq!(ymd(2024, 2, 29).format_dmy(), "29.02.2024");
+        assert_eq!(ymd(999, 1, 2).format_dmy(), "02.01.0999");
+
+        assert!(NaiveDate::from_dmy_str("29.02.2023").is_err());
+        assert!(NaiveDate::from_dmy_str("31.04.2024").is_err());
+        assert!(NaiveDate::from_dmy_str("00.01.2024").is_err());
+        assert!(NaiveDate::from_dmy_str("01.13.2024").is_err());
+        assert!(NaiveDate::from_dmy_str("01/03/2024").is_err());
+        assert!(NaiveDate::from_dmy_str("01.03.").is_err());
+        assert!(NaiveDate::from_dmy_str("01.03.2024.").is_err());
+    }
+
+    #[test]
+    fn test_date_from_str_any() {
+        use super::DateParsePreferences;
+
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        let mut dmy = DateParsePreferences::new();
+        dmy.prefer_dmy = true;
+        let mdy = DateParsePreferences::new();
+        assert_eq!(DateParsePreferences::default(), mdy);
+
+        let unambiguous = [
+            ("2024-01-22", ymd(2024, 1, 22)),
+            ("2024/01/22", ymd(2024, 1, 22)),
+            ("2024.1.22", ymd(2024, 1, 22)),
+            ("20240122", ymd(2024, 1, 22)),
+            ("22/01/2024", ymd(2024, 1, 22)),
+            ("01/22/2024", ymd(2024, 1, 22)),
+            ("22-01-2024", ymd(2024, 1, 22)),
+            ("22.01.2024", ymd(2024, 1, 22)),
+            ("22-Jan-2024", ymd(2024, 1, 22)),
+            ("22 January 2024", ymd(2024, 1, 22)),
+            ("Jan 22 2024", ymd(2024, 1, 22)),
+            ("January 22, 2024", ymd(2024, 1, 22)),
+            ("Mon, 22 Jan 2024", ymd(2024, 1, 22)),
+            ("Monday, January 22, 2024", ymd(2024, 1, 22)),
+            ("  2024-01-22", ymd(2024, 1, 22)),
+            ("29/02/2024", ymd(2024, 2, 29)),
+        ];
+        for &(s, d) in &unambiguous {
+            assert_eq!(NaiveDate::from_str_any_with(s, &dmy), Ok(d), "{:?}", s);
+            assert_eq!(NaiveDate::from_str_any_with(s, &mdy), Ok(d), "{:?}", s);
+        }
+
+        let ambiguous = [
+            ("01/02/2024", ymd(2024, 2, 1), ymd(2024, 1, 2)),
+            ("03-04-2024", ymd(2024, 4, 3), ymd(2024, 3, 4)),
+            ("12.11.2024", ymd(2024, 11, 12), ymd(2024, 12, 11)),
+        ];
+        for &(s, d_dmy, d_mdy) in &ambiguous {
+            assert_eq!(NaiveDate::from_str_any_with(s, &dmy), Ok(d_dmy), "{:?}", s);
+            assert_eq!(NaiveDate::from_str_any_with(s, &mdy), Ok(d_mdy), "{:?}", s);
+        }
+        assert_eq!(NaiveDate::from_str_any("01/02/2024"), Ok(ymd(2024, 1, 2)));
+
+        // the same day and month is not really ambiguous
+        assert_eq!(NaiveDate::from_str_any_with("05/05/2024", &dmy), Ok(ymd(2024, 5, 5)));
+
+        assert!(NaiveDate::from_str_any("").is_err());
+        assert!(NaiveDate::from_str_any("2024").is_err());
+        assert!(NaiveDate::from_str_any("2024-01-22 extra").is_err());
+        assert!(NaiveDate::from_str_any("Tue, 22 Jan 2024").is_err()); // wrong weekday
+        assert!(NaiveDate::from_str_any("30/02/2024").is_err());
+        assert!(NaiveDate::from_str_any("13/13/2024").is_err());
+        assert!(NaiveDate::from_str_any("Foo 22 2024").is_err());
+
+        // the error from a matching format is preferred over format mismatches
+        assert_eq!(NaiveDate::from_str_any("2023-02-29"),
+                   NaiveDate::parse_from_str("2023-02-29", "%Y-%m-%d"));
+        assert_eq!(NaiveDate::from_str_any("30/02/2024"),
+                   NaiveDate::parse_from_str("30/02/2024", "%d/%m/%Y"));
+    }
+
     #[test]
     fn test_date_num_days_from_ce() {
         assert_eq!(NaiveDate::from_ymd(1, 1, 1).num_days_from_ce(), 1);
@@ -0,0 +1,251 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Inclusive interval of calendar dates.
+
+use std::fmt;
+use oldtime::Duration as OldDuration;
+
+use naive::NaiveDate;
+
+/// An inclusive interval of dates, from `start` through `end`.
+///
+/// It is always guaranteed that `start <= end`,
+/// so the range contains at least one date.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::naive::DateRange;
+///
+/// let stay = DateRange::new(NaiveDate::from_ymd(2015, 9, 5), NaiveDate::from_ymd(2015, 9, 8))
+///                      .unwrap();
+/// assert_eq!(stay.len_days(), 4);
+/// assert!(stay.contains(NaiveDate::from_ymd(2015, 9, 8)));
+/// assert_eq!(stay.iter().last(), Some(NaiveDate::from_ymd(2015, 9, 8)));
+/// ~~~~
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct DateRange {
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl DateRange {
+    /// Makes a new `DateRange` from `start` through `end` inclusive.
+    ///
+    /// Returns `None` when `start` is later than `end`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::naive::DateRange;
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert!(DateRange::new(d, d).is_some());
+    /// assert!(DateRange::new(d, d.succ()).is_some());
+    /// assert!(DateRange::new(d.succ(), d).is_none());
+    /// ~~~~
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Option<DateRange> {
+        if start <= end {
+            Some(DateRange { start: start, end: end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the first date in the range.
+    #[inline]
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Returns the last date in the range.
+    #[inline]
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Returns `true` if `date` is within the range, including both ends.
+    #[inline]
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Returns `true` if two ranges have at least one date in common.
+    ///
+    /// Adjacent ranges (one ending on the day before the other starts) do not overlap.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::naive::DateRange;
+    ///
+    /// let range = |(y1, m1, d1), (y2, m2, d2)| {
+    ///     DateRange::new(NaiveDate::from_ymd(y1, m1, d1), NaiveDate::from_ymd(y2, m2, d2))
+    ///         .unwrap()
+    /// };
+    ///
+    /// let sep = range((2015, 9, 1), (2015, 9, 30));
+    /// assert!(sep.overlaps(&range((2015, 9, 30), (2015, 10, 31))));
+    /// assert!(!sep.overlaps(&range((2015, 10, 1), (2015, 10, 31))));
+    /// ~~~~
+    #[inline]
+    pub fn overlaps(&self, other: &DateRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Returns the number of dates in the range, which is at least 1.
+    #[inline]
+    pub fn len_days(&self) -> i64 {
+        self.end.signed_duration_since(self.start).num_days() + 1
+    }
+
+    /// Returns the length of the range as a `Duration` of whole days.
+    ///
+    /// As both ends are inclusive, this is one day longer than `end - start`;
+    /// a single-day range lasts for `Duration::days(1)`.
+    #[inline]
+    pub fn duration(&self) -> OldDuration {
+        OldDuration::days(self.len_days())
+    }
+
+    /// Returns an iterator over all dates in the range in the ascending order.
+    #[inline]
+    pub fn iter(&self) -> DateRangeIter {
+        DateRangeIter { next: Some(self.start), end: self.end }
+    }
+}
+
+impl IntoIterator for DateRange {
+    type Item = NaiveDate;
+    type IntoIter = DateRangeIter;
+
+    #[inline]
+    fn into_iter(self) -> DateRangeIter {
+        self.iter()
+    }
+}
+
+/// The `Debug` output of the date range `r` is `start..=end` of the `Debug` output of each date.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::naive::DateRange;
+///
+/// let r = DateRange::new(NaiveDate::from_ymd(2015, 9, 5), NaiveDate::from_ymd(2015, 9, 8));
+/// assert_eq!(format!("{:?}", r.unwrap()), "2015-09-05..=2015-09-08");
+/// ~~~~
+impl fmt::Debug for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}..={:?}", self.start, self.end)
+    }
+}
+
+/// An iterator over the dates in a [`DateRange`](./struct.DateRange.html).
+#[derive(Debug, Clone)]
+pub struct DateRangeIter {
+    next: Option<NaiveDate>,
+    end: NaiveDate,
+}
+
+impl Iterator for DateRangeIter {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        let current = try_opt!(self.next);
+        // `succ_opt` is not called for the last date, which may be `MAX_DATE`
+        self.next = if current < self.end { current.succ_opt() } else { None };
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(next) => {
+                let len = self.end.signed_duration_since(next).num_days() as usize + 1;
+                (len, Some(len))
+            }
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl ExactSizeIterator for DateRangeIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::DateRange;
+    use naive::{NaiveDate, MAX_DATE};
+    use oldtime::Duration;
+
+    fn range(start: (i32, u32, u32), end: (i32, u32, u32)) -> DateRange {
+        DateRange::new(NaiveDate::from_ymd(start.0, start.1, start.2),
+                       NaiveDate::from_ymd(end.0, end.1, end.2)).unwrap()
+    }
+
+    #[test]
+    fn test_date_range_new() {
+        let d = NaiveDate::from_ymd(2016, 2, 29);
+        assert!(DateRange::new(d, d).is_some());
+        assert!(DateRange::new(d.pred(), d).is_some());
+        assert!(DateRange::new(d, d.pred()).is_none());
+    }
+
+    #[test]
+    fn test_date_range_single_day() {
+        let d = NaiveDate::from_ymd(2016, 2, 29);
+        let r = DateRange::new(d, d).unwrap();
+        assert_eq!(r.len_days(), 1);
+        assert_eq!(r.duration(), Duration::days(1));
+        assert!(r.contains(d));
+        assert!(!r.contains(d.pred()));
+        assert!(!r.contains(d.succ()));
+        assert!(r.overlaps(&r));
+        assert_eq!(r.iter().collect::<Vec<_>>(), vec![d]);
+    }
+
+    #[test]
+    fn test_date_range_overlaps() {
+        let r = range((2016, 2, 1), (2016, 2, 29));
+        assert!(r.overlaps(&range((2016, 1, 1), (2016, 2, 1))));
+        assert!(r.overlaps(&range((2016, 2, 29), (2016, 3, 31))));
+        assert!(r.overlaps(&range((2016, 2, 10), (2016, 2, 20))));
+        assert!(r.overlaps(&range((2016, 1, 1), (2016, 12, 31))));
+        assert!(range((2016, 2, 10), (2016, 2, 20)).overlaps(&r));
+
+        // adjacent ranges do not overlap
+        assert!(!r.overlaps(&range((2016, 1, 1), (2016, 1, 31))));
+        assert!(!r.overlaps(&range((2016, 3, 1), (2016, 3, 31))));
+        assert!(!range((2016, 3, 1), (2016, 3, 31)).overlaps(&r));
+    }
+
+    #[test]
+    fn test_date_range_len() {
+        assert_eq!(range((2016, 1, 1), (2016, 12, 31)).len_days(), 366);
+        assert_eq!(range((2015, 1, 1), (2015, 12, 31)).len_days(), 365);
+        assert_eq!(range((2015, 12, 31), (2016, 1, 1)).duration(), Duration::days(2));
+    }
+
+    #[test]
+    fn test_date_range_iter() {
+        let r = range((2015, 12, 30), (2016, 1, 2));
+        let mut it = r.iter();
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next(), Some(NaiveDate::from_ymd(2015, 12, 30)));
+        assert_eq!(it.next(), Some(NaiveDate::from_ymd(2015, 12, 31)));
+        assert_eq!(it.next(), Some(NaiveDate::from_ymd(2016, 1, 1)));
+        assert_eq!(it.len(), 1);
+        assert_eq!(it.next(), Some(NaiveDate::from_ymd(2016, 1, 2)));
+        assert_eq!(it.len(), 0);
+        assert_eq!(it.next(), None);
+        assert_eq!(r.into_iter().count() as i64, r.len_days());
+
+        // should not overflow at the end of the representable range
+        let r = DateRange::new(MAX_DATE.pred(), MAX_DATE).unwrap();
+        assert_eq!(r.iter().collect::<Vec<_>>(), vec![MAX_DATE.pred(), MAX_DATE]);
+    }
+}
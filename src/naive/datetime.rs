@@ -4,13 +4,16 @@
 //! ISO 8601 date and time without timezone.
 
 use std::{str, fmt, hash};
+use std::borrow::Borrow;
 use std::ops::{Add, Sub, AddAssign, SubAssign};
 use num_traits::ToPrimitive;
 use oldtime::Duration as OldDuration;
 
-use {Weekday, Timelike, Datelike};
+use {Weekday, Timelike, Datelike, DateTime};
 use div::div_mod_floor;
-use naive::{NaiveTime, NaiveDate, IsoWeek};
+use offset::{TimeZone, LocalResult};
+use naive::{NaiveTime, NaiveDate, IsoWeek, DateTimeDiff};
+use naive::diff::add_months;
 use format::{Item, Numeric, Pad, Fixed};
 use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItems};
 
@@ -143,6 +146,33 @@ impl NaiveDateTime {
         }
     }
 
+    /// Makes a new `NaiveDateTime` from the fractional Modified Julian Day (MJD) number,
+    /// where 0.0 is the midnight of November 17, 1858
+    /// and the fractional part denotes the time of the day.
+    ///
+    /// The time is rounded to the nearest microsecond,
+    /// which is about the precision of `f64` for the current dates.
+    ///
+    /// Returns `None` on the out-of-range date and non-finite numbers.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDateTime, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDateTime::from_mjd_f64(51_544.5),
+    ///            Some(NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0)));
+    /// assert_eq!(NaiveDateTime::from_mjd_f64(-0.25),
+    ///            Some(NaiveDate::from_ymd(1858, 11, 16).and_hms(18, 0, 0)));
+    /// assert_eq!(NaiveDateTime::from_mjd_f64(1e12), None);
+    /// ~~~~
+    pub fn from_mjd_f64(mjd: f64) -> Option<NaiveDateTime> {
+        let date = try_opt!(NaiveDate::from_mjd(mjd));
+        let micros = ((mjd - mjd.floor()) * 86_400_000_000.0).round() as i64;
+        // the rounding may carry into the next day
+        date.and_hms(0, 0, 0).checked_add_signed(OldDuration::microseconds(micros))
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDateTime`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -210,6 +240,51 @@ impl NaiveDateTime {
         parsed.to_naive_datetime_with_offset(0) // no offset adjustment
     }
 
+    /// Returns an RFC 7231 HTTP date string such as `Tue, 15 Nov 1994 08:12:31 GMT`.
+    ///
+    /// HTTP dates are always in UTC (or GMT), so this does not do any offset adjustment;
+    /// the caller should convert the date and time to UTC first.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(1994, 11, 15).and_hms(8, 12, 31);
+    /// assert_eq!(dt.to_http_date(), "Tue, 15 Nov 1994 08:12:31 GMT");
+    /// ~~~~
+    pub fn to_http_date(&self) -> String {
+        self.format(HTTP_IMF_FIXDATE).to_string()
+    }
+
+    /// Parses an HTTP date string into a `NaiveDateTime` value in UTC.
+    ///
+    /// As required by RFC 7231, this accepts the preferred IMF-fixdate format
+    /// (`Sun, 06 Nov 1994 08:49:37 GMT`) and two obsolete formats,
+    /// RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`) and ANSI C `asctime()`
+    /// (`Sun Nov  6 08:49:37 1994`).
+    ///
+    /// The two-digit year in the RFC 850 format is interpreted relative to the current year:
+    /// a year that appears to be more than 50 years in the future is taken as
+    /// the most recent year in the past with the same last two digits.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDateTime, NaiveDate};
+    ///
+    /// let dt = NaiveDate::from_ymd(1994, 11, 6).and_hms(8, 49, 37);
+    /// assert_eq!(NaiveDateTime::parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(dt));
+    /// assert_eq!(NaiveDateTime::parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(dt));
+    /// assert_eq!(NaiveDateTime::parse_http_date("Sun Nov  6 08:49:37 1994"), Ok(dt));
+    /// assert!(NaiveDateTime::parse_http_date("Sun, 06 Nov 1994 08:49:37 +0900").is_err());
+    /// ~~~~
+    #[cfg(feature = "clock")]
+    pub fn parse_http_date(s: &str) -> ParseResult<NaiveDateTime> {
+        use offset::Utc;
+        parse_http_date_relative_to(s, Utc::now().year())
+    }
+
     /// Retrieves a date component.
     ///
     /// # Example
@@ -315,6 +390,9 @@ impl NaiveDateTime {
     /// (If this is a problem, please file an issue to let me know what domain
     /// needs nanosecond precision over millenia, I'm curious.)
     ///
+    /// Use [`timestamp_nanos_opt`](#method.timestamp_nanos_opt)
+    /// when the date and time may fall out of this range.
+    ///
     /// # Example
     ///
     /// ~~~~
@@ -339,6 +417,42 @@ impl NaiveDateTime {
         as_ns + i64::from(self.timestamp_subsec_nanos())
     }
 
+    /// Returns the number of non-leap *nanoseconds* since midnight on January 1, 1970.
+    ///
+    /// Note that this does *not* account for the timezone!
+    /// The true "UNIX timestamp" would count seconds since the midnight *UTC* on the epoch.
+    ///
+    /// Returns `None` when the result does not fit in `i64`, that is,
+    /// for the date and time before 1677-09-21T00:12:43.145224192
+    /// or after 2262-04-11T23:47:16.854775807.
+    /// (The plain [`timestamp`](#method.timestamp) in seconds never overflows.)
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(1970, 1, 1).and_hms_nano(0, 0, 1, 444);
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(1_000_000_444));
+    ///
+    /// let dt = NaiveDate::from_ymd(2262, 4, 11).and_hms_nano(23, 47, 16, 854_775_807);
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(9_223_372_036_854_775_807));
+    /// assert_eq!(NaiveDate::from_ymd(2262, 4, 12).and_hms(0, 0, 0).timestamp_nanos_opt(), None);
+    /// assert_eq!(NaiveDate::from_ymd(1677, 9, 20).and_hms(0, 0, 0).timestamp_nanos_opt(), None);
+    /// ~~~~
+    #[inline]
+    pub fn timestamp_nanos_opt(&self) -> Option<i64> {
+        let mut secs = self.timestamp();
+        let mut nanos = i64::from(self.timestamp_subsec_nanos());
+        // borrow a second so that the earliest representable instant does not overflow
+        if secs < 0 && nanos > 0 {
+            secs += 1;
+            nanos -= 1_000_000_000;
+        }
+        let as_ns = try_opt!(secs.checked_mul(1_000_000_000));
+        as_ns.checked_add(nanos)
+    }
+
     /// Returns the number of milliseconds since the last whole non-leap second.
     ///
     /// The return value ranges from 0 to 999,
@@ -618,6 +732,118 @@ impl NaiveDateTime {
         self.date.signed_duration_since(rhs.date) + self.time.signed_duration_since(rhs.time)
     }
 
+    /// Returns the difference `self - rhs` in years, months, days, hours, minutes,
+    /// seconds and nanoseconds.
+    ///
+    /// The difference is counted from `rhs`: the largest number of whole months
+    /// which can be added to `rhs` without passing `self` is taken first
+    /// (clamping the day of month as in [`DateTimeDiff::apply_to`](./struct.DateTimeDiff.html#method.apply_to)),
+    /// and the remainder is split into smaller units.
+    /// Therefore `self.diff(&rhs).apply_to(rhs)` is always `Some(self)`.
+    /// All fields have the same sign, and they are negative when `self` is earlier than `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// let d = from_ymd(2016, 3, 1).and_hms(6, 0, 0).diff(&from_ymd(2016, 1, 31).and_hms(0, 0, 0));
+    /// assert_eq!((d.years, d.months, d.days, d.hours), (0, 1, 1, 6));
+    ///
+    /// let d = from_ymd(2015, 2, 28).and_hms(0, 0, 0).diff(&from_ymd(2016, 2, 29).and_hms(0, 0, 0));
+    /// assert_eq!((d.years, d.months, d.days, d.hours), (-1, 0, 0, 0));
+    /// ~~~~
+    pub fn diff(&self, rhs: &NaiveDateTime) -> DateTimeDiff {
+        let (lhs, rhs) = (*self, *rhs);
+        let mut months = (i64::from(lhs.year()) - i64::from(rhs.year())) * 12 +
+                         i64::from(lhs.month0()) - i64::from(rhs.month0());
+
+        // the month of `lhs` always exists, so do the adjacent months towards `rhs`
+        let mut anchor = add_months(rhs, months).expect("out-of-range date");
+        if lhs >= rhs && anchor > lhs {
+            months -= 1;
+            anchor = add_months(rhs, months).expect("out-of-range date");
+        } else if lhs < rhs && anchor < lhs {
+            months += 1;
+            anchor = add_months(rhs, months).expect("out-of-range date");
+        }
+
+        let rest = lhs.signed_duration_since(anchor);
+        let secs = rest.num_seconds();
+        let nanos = (rest - OldDuration::seconds(secs)).num_nanoseconds().unwrap();
+        DateTimeDiff {
+            years: (months / 12) as i32,
+            months: (months % 12) as i32,
+            days: (secs / 86_400) as i32,
+            hours: (secs / 3_600 % 24) as i32,
+            minutes: (secs / 60 % 60) as i32,
+            seconds: (secs % 60) as i32,
+            nanoseconds: nanos as i32,
+        }
+    }
+
+    /// Restricts the value to the inclusive range from `min` to `max`.
+    ///
+    /// Returns `min` if `self` is earlier than `min`, `max` if `self` is later than `max`,
+    /// and `self` otherwise.
+    ///
+    /// Panics if `min` is later than `max`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// let (min, max) = (d.and_hms(9, 0, 0), d.and_hms(17, 0, 0));
+    /// assert_eq!(d.and_hms(12, 30, 0).clamp(min, max), d.and_hms(12, 30, 0));
+    /// assert_eq!(d.and_hms(23, 56, 4).clamp(min, max), max);
+    /// assert_eq!(d.and_hms(8, 59, 59).clamp(min, max), min);
+    /// ~~~~
+    pub fn clamp(self, min: NaiveDateTime, max: NaiveDateTime) -> NaiveDateTime {
+        assert!(min <= max, "`min` should not be later than `max`");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Converts the local date and time to a `DateTime` in given time zone,
+    /// using `resolver` to pick one when the local time is ambiguous.
+    ///
+    /// The resolver receives the earliest and latest possible results;
+    /// [`offset::resolve_earliest`](../offset/fn.resolve_earliest.html),
+    /// [`offset::resolve_latest`](../offset/fn.resolve_latest.html) and
+    /// [`offset::resolve_error`](../offset/fn.resolve_error.html)
+    /// provide the common strategies.
+    /// Returns `None` when the local time does not exist in given time zone
+    /// or the resolver returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, FixedOffset, TimeZone};
+    /// use chrono::offset::resolve_earliest;
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// let dt = NaiveDate::from_ymd(2015, 9, 5).and_hms(23, 56, 4);
+    /// assert_eq!(dt.with_timezone_and_resolve(&tz, resolve_earliest),
+    ///            Some(tz.ymd(2015, 9, 5).and_hms(23, 56, 4)));
+    /// ~~~~
+    pub fn with_timezone_and_resolve<Tz, F>(&self, tz: &Tz, resolver: F) -> Option<DateTime<Tz>>
+            where Tz: TimeZone, F: FnOnce(DateTime<Tz>, DateTime<Tz>) -> Option<DateTime<Tz>> {
+        match tz.from_local_datetime(self) {
+            LocalResult::None => None,
+            LocalResult::Single(dt) => Some(dt),
+            LocalResult::Ambiguous(earliest, latest) => resolver(earliest, latest),
+        }
+    }
+
     /// Formats the combined date and time with the specified formatting items.
     /// Otherwise it is same to the ordinary [`format`](#method.format) method.
     ///
@@ -646,8 +872,8 @@ impl NaiveDateTime {
     /// assert_eq!(format!("{}", dt.format_with_items(fmt)), "2015-09-05 23:56:04");
     /// ~~~~
     #[inline]
-    pub fn format_with_items<'a, I>(&self, items: I) -> DelayedFormat<I>
-            where I: Iterator<Item=Item<'a>> + Clone {
+    pub fn format_with_items<'a, I, B>(&self, items: I) -> DelayedFormat<I>
+            where I: Iterator<Item=B> + Clone, B: Borrow<Item<'a>> {
         DelayedFormat::new(Some(self.date), Some(self.time), items)
     }
 
@@ -689,6 +915,38 @@ impl NaiveDateTime {
     }
 }
 
+// HTTP date formats, see RFC 7231 section 7.1.1.1.
+const HTTP_IMF_FIXDATE: &'static str = "%a, %d %b %Y %H:%M:%S GMT";
+const HTTP_RFC850_DATE: &'static str = "%A, %d-%b-%y %H:%M:%S GMT";
+const HTTP_ASCTIME_DATE: &'static str = "%a %b %e %H:%M:%S %Y";
+
+/// Parses an HTTP date string, resolving the two-digit year of the RFC 850 format
+/// against the given current year. Errors from the IMF-fixdate format are preferred.
+#[cfg_attr(not(feature = "clock"), allow(dead_code))]
+fn parse_http_date_relative_to(s: &str, current_year: i32) -> ParseResult<NaiveDateTime> {
+    let err = match NaiveDateTime::parse_from_str(s, HTTP_IMF_FIXDATE) {
+        Ok(dt) => return Ok(dt),
+        Err(e) => e,
+    };
+
+    let mut parsed = Parsed::new();
+    if parse(&mut parsed, s, StrftimeItems::new(HTTP_RFC850_DATE)).is_ok() {
+        if let Some(yy) = parsed.year_mod_100 {
+            // the year with the same last two digits, no more than 50 years in the future
+            let mut year = current_year - current_year % 100 + yy;
+            if year > current_year + 50 {
+                year -= 100;
+            } else if year <= current_year - 50 {
+                year += 100;
+            }
+            parsed.year = Some(year);
+        }
+        return parsed.to_naive_datetime_with_offset(0);
+    }
+
+    NaiveDateTime::parse_from_str(s, HTTP_ASCTIME_DATE).map_err(|_| err)
+}
+
 impl Datelike for NaiveDateTime {
     /// Returns the year number in the [calendar date](./index.html#calendar-date).
     ///
@@ -2340,6 +2598,40 @@ mod tests {
                    Ok(ymdhmsn(2015, 7, 24, 12, 49, 49, 918273645)));
     }
 
+    #[test]
+    fn test_datetime_http_date() {
+        use super::parse_http_date_relative_to;
+
+        let ymdhms = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s);
+        let parse = |s: &str| parse_http_date_relative_to(s, 2018);
+
+        let dt = ymdhms(1994, 11, 6, 8, 49, 37);
+        assert_eq!(dt.to_http_date(), "Sun, 06 Nov 1994 08:49:37 GMT");
+        assert_eq!(parse("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(dt));
+        assert_eq!(parse("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(dt));
+        assert_eq!(parse("Sun Nov  6 08:49:37 1994"), Ok(dt));
+        assert_eq!(parse("Wed Nov 16 08:49:37 1994"), Ok(ymdhms(1994, 11, 16, 8, 49, 37)));
+
+        let dt = ymdhms(2018, 2, 28, 23, 59, 59);
+        assert_eq!(parse(&dt.to_http_date()), Ok(dt));
+
+        // two-digit years are no more than 50 years in the future
+        assert_eq!(parse("Sunday, 01-Jan-68 00:00:00 GMT"), Ok(ymdhms(2068, 1, 1, 0, 0, 0)));
+        assert_eq!(parse("Wednesday, 01-Jan-69 00:00:00 GMT"), Ok(ymdhms(1969, 1, 1, 0, 0, 0)));
+        assert_eq!(parse("Thursday, 01-Jan-70 00:00:00 GMT"), Ok(ymdhms(1970, 1, 1, 0, 0, 0)));
+        assert_eq!(parse("Saturday, 01-Jan-00 00:00:00 GMT"), Ok(ymdhms(2000, 1, 1, 0, 0, 0)));
+        assert_eq!(parse_http_date_relative_to("Monday, 01-Jan-80 00:00:00 GMT", 2050),
+                   Ok(ymdhms(2080, 1, 1, 0, 0, 0)));
+
+        // inconsistent weekdays and other formats are errors
+        assert!(parse("Mon, 06 Nov 1994 08:49:37 GMT").is_err());
+        assert!(parse("Monday, 06-Nov-94 08:49:37 GMT").is_err());
+        assert!(parse("Sun, 06 Nov 1994 08:49:37 UTC").is_err());
+        assert!(parse("Sun, 06 Nov 1994 08:49:37").is_err());
+        assert!(parse("1994-11-06T08:49:37Z").is_err());
+        assert!(parse("").is_err());
+    }
+
     #[test]
     fn test_datetime_format() {
         let dt = NaiveDate::from_ymd(2010, 9, 8).and_hms_milli(7, 6, 54, 321);
@@ -2380,4 +2672,95 @@ mod tests {
             NaiveDateTime::from_timestamp(nanos / A_BILLION, (nanos % A_BILLION) as u32)
         );
     }
+
+    #[test]
+    fn test_timestamp_nanos_opt() {
+        use std::i64;
+
+        let parse = |s: &str| s.parse::<NaiveDateTime>().unwrap();
+        assert_eq!(parse("1970-01-01T00:00:00").timestamp_nanos_opt(), Some(0));
+        assert_eq!(parse("1969-12-31T23:59:59.999999999").timestamp_nanos_opt(), Some(-1));
+        assert_eq!(parse("2262-04-11T23:47:16.854775807").timestamp_nanos_opt(),
+                   Some(i64::MAX));
+        assert_eq!(parse("2262-04-11T23:47:16.854775808").timestamp_nanos_opt(), None);
+        assert_eq!(parse("1677-09-21T00:12:43.145224192").timestamp_nanos_opt(),
+                   Some(i64::MIN));
+        assert_eq!(parse("1677-09-21T00:12:43.145224191").timestamp_nanos_opt(), None);
+        assert_eq!(parse("+12345-01-01T00:00:00").timestamp_nanos_opt(), None);
+
+        let dt = parse("2001-09-09T01:46:40.555");
+        assert_eq!(dt.timestamp_nanos_opt(), Some(dt.timestamp_nanos()));
+    }
+
+    #[test]
+    fn test_datetime_from_mjd_f64() {
+        let ymdhms = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s);
+        assert_eq!(NaiveDateTime::from_mjd_f64(0.0), Some(ymdhms(1858, 11, 17, 0, 0, 0)));
+        assert_eq!(NaiveDateTime::from_mjd_f64(51_544.0), Some(ymdhms(2000, 1, 1, 0, 0, 0)));
+        assert_eq!(NaiveDateTime::from_mjd_f64(51_544.25), Some(ymdhms(2000, 1, 1, 6, 0, 0)));
+        assert_eq!(NaiveDateTime::from_mjd_f64(51_543.75), Some(ymdhms(1999, 12, 31, 18, 0, 0)));
+        assert_eq!(NaiveDateTime::from_mjd_f64(-1.5), Some(ymdhms(1858, 11, 15, 12, 0, 0)));
+
+        // one second, well within the precision
+        let dt = NaiveDateTime::from_mjd_f64(58_000.0 + 1.0 / 86_400.0).unwrap();
+        assert_eq!(dt, ymdhms(2017, 9, 4, 0, 0, 1));
+
+        // rounded up to the next midnight
+        let dt = NaiveDateTime::from_mjd_f64(1.0 - 1e-12).unwrap();
+        assert_eq!(dt, ymdhms(1858, 11, 18, 0, 0, 0));
+
+        assert_eq!(NaiveDateTime::from_mjd_f64(::std::f64::NAN), None);
+        assert_eq!(NaiveDateTime::from_mjd_f64(::std::f64::INFINITY), None);
+        assert_eq!(NaiveDateTime::from_mjd_f64(-1e12), None);
+    }
+
+    #[test]
+    fn test_datetime_clamp() {
+        let ymdhms = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s);
+        let (min, max) = (ymdhms(2016, 2, 29, 9, 0, 0), ymdhms(2016, 3, 1, 17, 0, 0));
+        assert_eq!(ymdhms(2016, 2, 29, 23, 0, 0).clamp(min, max), ymdhms(2016, 2, 29, 23, 0, 0));
+        assert_eq!(ymdhms(2016, 2, 29, 8, 59, 59).clamp(min, max), min);
+        assert_eq!(ymdhms(2016, 3, 1, 17, 0, 1).clamp(min, max), max);
+        assert_eq!(ymdhms(2016, 3, 2, 0, 0, 0).clamp(min, max), max);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(max.clamp(min, min), min);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_datetime_clamp_invalid_range() {
+        let ymdhms = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s);
+        ymdhms(2016, 2, 29, 12, 0, 0).clamp(ymdhms(2016, 3, 1, 0, 0, 0),
+                                            ymdhms(2016, 2, 29, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_datetime_with_timezone_and_resolve() {
+        use offset::{TestCet, Utc};
+        use offset::{resolve_earliest, resolve_latest, resolve_error};
+
+        let ymdhms = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s);
+        let utc = |dt: Option<::DateTime<TestCet>>| dt.map(|dt| dt.with_timezone(&Utc).naive_utc());
+
+        // unique
+        let dt = ymdhms(2015, 6, 1, 12, 0, 0);
+        assert_eq!(utc(dt.with_timezone_and_resolve(&TestCet, resolve_error)),
+                   Some(ymdhms(2015, 6, 1, 10, 0, 0)));
+
+        // nonexistent
+        let dt = ymdhms(2015, 3, 29, 2, 30, 0);
+        assert_eq!(utc(dt.with_timezone_and_resolve(&TestCet, resolve_earliest)), None);
+        assert_eq!(utc(dt.with_timezone_and_resolve(&TestCet, resolve_latest)), None);
+
+        // ambiguous
+        let dt = ymdhms(2015, 10, 25, 2, 30, 0);
+        assert_eq!(utc(dt.with_timezone_and_resolve(&TestCet, resolve_earliest)),
+                   Some(ymdhms(2015, 10, 25, 0, 30, 0)));
+        assert_eq!(utc(dt.with_timezone_and_resolve(&TestCet, resolve_latest)),
+                   Some(ymdhms(2015, 10, 25, 1, 30, 0)));
+        assert_eq!(utc(dt.with_timezone_and_resolve(&TestCet, resolve_error)), None);
+        assert_eq!(utc(dt.with_timezone_and_resolve(&TestCet, |a, b| Some(::std::cmp::max(a, b)))),
+                   Some(ymdhms(2015, 10, 25, 1, 30, 0)));
+    }
 }
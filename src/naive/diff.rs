@@ -0,0 +1,207 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Calendar difference between two dates and times.
+
+use num_traits::ToPrimitive;
+use oldtime::Duration as OldDuration;
+
+use Datelike;
+use div::div_mod_floor;
+use naive::{NaiveDate, NaiveDateTime};
+
+/// The difference between two `NaiveDateTime`s in the calendar units,
+/// as returned by [`NaiveDateTime::diff`](./struct.NaiveDateTime.html#method.diff).
+///
+/// All fields have the same sign for the difference returned by `NaiveDateTime::diff`,
+/// and no field exceeds its natural maximum (e.g. `months` is within -11 and 11,
+/// `days` is within -30 and 30 and `hours` is within -23 and 23).
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::naive::DateTimeDiff;
+///
+/// let birth = NaiveDate::from_ymd(1990, 5, 17).and_hms(8, 30, 0);
+/// let now = NaiveDate::from_ymd(2018, 7, 2).and_hms(12, 0, 0);
+///
+/// let age = now.diff(&birth);
+/// assert_eq!((age.years, age.months, age.days), (28, 1, 15));
+/// assert_eq!((age.hours, age.minutes), (3, 30));
+/// assert_eq!(age.apply_to(birth), Some(now));
+/// assert_eq!(now.diff(&now), DateTimeDiff::default());
+/// ~~~~
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug, Default)]
+pub struct DateTimeDiff {
+    /// The number of years.
+    pub years: i32,
+    /// The number of months.
+    pub months: i32,
+    /// The number of days.
+    pub days: i32,
+    /// The number of hours.
+    pub hours: i32,
+    /// The number of minutes.
+    pub minutes: i32,
+    /// The number of seconds.
+    pub seconds: i32,
+    /// The number of nanoseconds.
+    pub nanoseconds: i32,
+}
+
+impl DateTimeDiff {
+    /// Adds the difference to given date and time.
+    ///
+    /// Years and months are added first, clamping the day of month to the last day
+    /// of the resulting month (so January 31 plus one month is February 28 or 29),
+    /// and then the remaining fields are added as an exact duration.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::naive::DateTimeDiff;
+    ///
+    /// let mut diff = DateTimeDiff::default();
+    /// diff.months = 1;
+    /// diff.hours = 12;
+    /// assert_eq!(diff.apply_to(NaiveDate::from_ymd(2016, 1, 31).and_hms(0, 0, 0)),
+    ///            Some(NaiveDate::from_ymd(2016, 2, 29).and_hms(12, 0, 0)));
+    /// ~~~~
+    pub fn apply_to(&self, dt: NaiveDateTime) -> Option<NaiveDateTime> {
+        let months = i64::from(self.years) * 12 + i64::from(self.months);
+        let dt = try_opt!(add_months(dt, months));
+        let secs = i64::from(self.days) * 86_400 + i64::from(self.hours) * 3_600 +
+                   i64::from(self.minutes) * 60 + i64::from(self.seconds);
+        let rhs = OldDuration::seconds(secs) + OldDuration::nanoseconds(i64::from(self.nanoseconds));
+        dt.checked_add_signed(rhs)
+    }
+}
+
+/// Adds given number of months to the date and time,
+/// clamping the day of month to the last day of the resulting month.
+pub fn add_months(dt: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let date = dt.date();
+    let (year, month0) = div_mod_floor(i64::from(date.year()) * 12 + i64::from(date.month0()) +
+                                       months, 12);
+    let year = try_opt!(year.to_i32());
+    let month = month0 as u32 + 1;
+
+    // every month has at least 28 days
+    let mut day = date.day();
+    loop {
+        if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+            return Some(date.and_time(dt.time()));
+        }
+        if day <= 28 {
+            return None;
+        }
+        day -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateTimeDiff, add_months};
+    use Datelike;
+    use naive::{NaiveDate, MIN_DATE, MAX_DATE};
+
+    fn diff(years: i32, months: i32, days: i32, hours: i32, minutes: i32, seconds: i32,
+            nanoseconds: i32) -> DateTimeDiff {
+        DateTimeDiff { years: years, months: months, days: days, hours: hours,
+                       minutes: minutes, seconds: seconds, nanoseconds: nanoseconds }
+    }
+
+    #[test]
+    fn test_add_months() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d).and_hms(1, 2, 3);
+        assert_eq!(add_months(ymd(2016, 1, 31), 1), Some(ymd(2016, 2, 29)));
+        assert_eq!(add_months(ymd(2015, 1, 31), 1), Some(ymd(2015, 2, 28)));
+        assert_eq!(add_months(ymd(2015, 1, 31), 3), Some(ymd(2015, 4, 30)));
+        assert_eq!(add_months(ymd(2015, 3, 31), -1), Some(ymd(2015, 2, 28)));
+        assert_eq!(add_months(ymd(2015, 12, 15), 1), Some(ymd(2016, 1, 15)));
+        assert_eq!(add_months(ymd(2015, 1, 15), -13), Some(ymd(2013, 12, 15)));
+        assert_eq!(add_months(ymd(2015, 1, 15), 0), Some(ymd(2015, 1, 15)));
+        assert_eq!(add_months(MAX_DATE.and_hms(0, 0, 0), 1), None);
+        assert_eq!(add_months(MIN_DATE.and_hms(0, 0, 0), -1), None);
+        assert_eq!(add_months(ymd(2015, 1, 15), i64::max_value() / 2), None);
+    }
+
+    #[test]
+    fn test_datetime_diff() {
+        let ymdhms = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s);
+
+        let d = ymdhms(2016, 2, 29, 12, 0, 0);
+        assert_eq!(d.diff(&d), DateTimeDiff::default());
+
+        // (later, earlier, later - earlier, earlier - later)
+        let cases = [
+            (ymdhms(2016, 3, 1, 0, 0, 0), ymdhms(2016, 1, 31, 0, 0, 0),
+             diff(0, 1, 1, 0, 0, 0, 0), diff(0, -1, -1, 0, 0, 0, 0)),
+            (ymdhms(2015, 3, 1, 0, 0, 0), ymdhms(2015, 1, 30, 0, 0, 0),
+             diff(0, 1, 1, 0, 0, 0, 0), diff(0, -1, -2, 0, 0, 0, 0)),
+            (ymdhms(2017, 2, 28, 0, 0, 0), ymdhms(2016, 2, 29, 0, 0, 0),
+             diff(1, 0, 0, 0, 0, 0, 0), diff(0, -11, -28, 0, 0, 0, 0)),
+            (ymdhms(2016, 1, 1, 0, 0, 0), ymdhms(2015, 12, 31, 23, 59, 59),
+             diff(0, 0, 0, 0, 0, 1, 0), diff(0, 0, 0, 0, 0, -1, 0)),
+            (ymdhms(2016, 6, 15, 8, 0, 0), ymdhms(2016, 5, 15, 9, 0, 0),
+             diff(0, 0, 30, 23, 0, 0, 0), diff(0, 0, -30, -23, 0, 0, 0)),
+            (ymdhms(2018, 7, 2, 12, 0, 0), ymdhms(1990, 5, 17, 8, 30, 0),
+             diff(28, 1, 15, 3, 30, 0, 0), diff(-28, -1, -16, -3, -30, 0, 0)),
+        ];
+        for &(later, earlier, forward, backward) in &cases {
+            assert_eq!(later.diff(&earlier), forward, "{} - {}", later, earlier);
+            assert_eq!(earlier.diff(&later), backward, "{} - {}", earlier, later);
+            assert_eq!(forward.apply_to(earlier), Some(later));
+            assert_eq!(backward.apply_to(later), Some(earlier));
+        }
+
+        let dt = NaiveDate::from_ymd(2016, 1, 1).and_hms_nano(0, 0, 0, 1);
+        assert_eq!(dt.diff(&ymdhms(2015, 12, 31, 23, 59, 59)), diff(0, 0, 0, 0, 0, 1, 1));
+        assert_eq!(MAX_DATE.and_hms(0, 0, 0).diff(&MIN_DATE.and_hms(0, 0, 0)).years,
+                   MAX_DATE.year() - MIN_DATE.year());
+    }
+
+    #[test]
+    fn test_datetime_diff_round_trip() {
+        // pseudo-random dates and times within 1900--2100, including the ends of months
+        let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = |n: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % n
+        };
+
+        let mut dts = Vec::new();
+        for _ in 0..200 {
+            let date = NaiveDate::from_ymd(1900 + next(200) as i32, 1 + next(12) as u32, 1);
+            let date = if next(3) == 0 {
+                add_months(date.and_hms(0, 0, 0), 1).unwrap().date().pred() // the last day
+            } else {
+                date.with_day(1 + next(28) as u32).unwrap()
+            };
+            dts.push(date.and_hms_nano(next(24) as u32, next(60) as u32, next(60) as u32,
+                                       next(1_000_000_000) as u32));
+        }
+
+        for a in &dts {
+            for b in &dts {
+                let d = a.diff(b);
+                assert_eq!(d.apply_to(*b), Some(*a), "{} - {} = {:?}", a, b, d);
+
+                let fields = [d.years, d.months, d.days, d.hours, d.minutes, d.seconds,
+                              d.nanoseconds];
+                let limits = [i32::max_value(), 11, 30, 23, 59, 59, 999_999_999];
+                for (&v, &limit) in fields.iter().zip(limits.iter()) {
+                    assert!(-limit <= v && v <= limit);
+                    assert!(if a >= b { v >= 0 } else { v <= 0 });
+                }
+            }
+        }
+    }
+
+}
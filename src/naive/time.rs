@@ -4,6 +4,7 @@
 //! ISO 8601 time without timezone.
 
 use std::{str, fmt, hash};
+use std::borrow::Borrow;
 use std::ops::{Add, Sub, AddAssign, SubAssign};
 use oldtime::Duration as OldDuration;
 
@@ -380,6 +381,47 @@ impl NaiveTime {
         Some(NaiveTime { secs: secs, frac: nano })
     }
 
+    /// Makes a new `NaiveTime` from the `(hour, minute, second, nanosecond)` tuple,
+    /// as returned by [`to_hmsn`](#method.to_hmsn).
+    ///
+    /// The nanosecond part can exceed 1,000,000,000
+    /// in order to represent the [leap second](#leap-second-handling).
+    ///
+    /// Returns `None` on invalid hour, minute, second and/or nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// assert_eq!(NaiveTime::from_hmsn((23, 56, 4, 12_345_678)),
+    ///            Some(NaiveTime::from_hms_nano(23, 56, 4, 12_345_678)));
+    /// assert_eq!(NaiveTime::from_hmsn((24, 0, 0, 0)), None);
+    /// ~~~~
+    #[inline]
+    pub fn from_hmsn((hour, min, sec, nano): (u32, u32, u32, u32)) -> Option<NaiveTime> {
+        NaiveTime::from_hms_nano_opt(hour, min, sec, nano)
+    }
+
+    /// Returns the hour, minute, second and nanosecond as a tuple.
+    ///
+    /// The nanosecond part can exceed 1,000,000,000 during the leap second.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_nano(23, 59, 59, 1_012_345_678);
+    /// assert_eq!(t.to_hmsn(), (23, 59, 59, 1_012_345_678));
+    /// assert_eq!(NaiveTime::from_hmsn(t.to_hmsn()), Some(t));
+    /// ~~~~
+    #[inline]
+    pub fn to_hmsn(&self) -> (u32, u32, u32, u32) {
+        let (hour, min, sec) = self.hms();
+        (hour, min, sec, self.frac)
+    }
+
     /// Makes a new `NaiveTime` from the number of seconds since midnight and nanosecond.
     ///
     /// The nanosecond part can exceed 1,000,000,000
@@ -429,6 +471,54 @@ impl NaiveTime {
         Some(NaiveTime { secs: secs, frac: nano })
     }
 
+    /// Makes a new `NaiveTime` from the decimal hours since midnight,
+    /// as commonly used by scientific data formats (e.g. `13.5` for 1:30 PM).
+    ///
+    /// The time is rounded to the nearest nanosecond.
+    /// `f64` has a resolution of about 0.01 nanoseconds near the end of the day,
+    /// so [`to_decimal_hours`](#method.to_decimal_hours) round-trips for every time
+    /// except leap seconds, but hours computed with the floating point arithmetic
+    /// may well be off by a few nanoseconds.
+    ///
+    /// Returns `None` when `hours` is NaN, negative or not less than 24.0.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// assert_eq!(NaiveTime::from_decimal_hours(13.5), Some(NaiveTime::from_hms(13, 30, 0)));
+    /// assert_eq!(NaiveTime::from_decimal_hours(0.001), Some(NaiveTime::from_hms_milli(0, 0, 3, 600)));
+    /// assert_eq!(NaiveTime::from_decimal_hours(-1.0), None);
+    /// assert_eq!(NaiveTime::from_decimal_hours(24.0), None);
+    /// ~~~~
+    pub fn from_decimal_hours(hours: f64) -> Option<NaiveTime> {
+        if !(hours >= 0.0 && hours < 24.0) { return None; }
+        // a value just below 24.0 should not be rounded up to the next midnight
+        let nanos = (hours * 3_600_000_000_000.0).round().min(86_399_999_999_999.0) as u64;
+        let secs = (nanos / 1_000_000_000) as u32;
+        let nano = (nanos % 1_000_000_000) as u32;
+        NaiveTime::from_num_seconds_from_midnight_opt(secs, nano)
+    }
+
+    /// Returns the decimal hours since midnight, e.g. `13.5` for 1:30 PM.
+    ///
+    /// The result is always less than 24.0 except for the leap second at 23:59:60,
+    /// which is counted as an extension of the last second of the day.
+    /// See [`from_decimal_hours`](#method.from_decimal_hours) for the precision.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// assert_eq!(NaiveTime::from_hms(13, 30, 0).to_decimal_hours(), 13.5);
+    /// assert_eq!(NaiveTime::from_hms_milli(6, 0, 36, 0).to_decimal_hours(), 6.01);
+    /// ~~~~
+    pub fn to_decimal_hours(&self) -> f64 {
+        f64::from(self.secs) / 3_600.0 + f64::from(self.frac) / 3_600_000_000_000.0
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveTime`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -696,6 +786,80 @@ impl NaiveTime {
         OldDuration::seconds(secs + adjust) + OldDuration::nanoseconds(frac)
     }
 
+    /// Rounds the time to the nearest multiple of `duration` since the midnight.
+    /// Halfway values are rounded up.
+    ///
+    /// Rounding up past the last multiple in the day wraps around to the midnight.
+    /// A [leap second](#leap-second-handling) is treated as a part of the following second.
+    /// The time is returned unmodified when `duration` is one day or longer.
+    ///
+    /// Panics when `duration` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveTime;
+    /// use time::Duration;
+    ///
+    /// let from_hms = NaiveTime::from_hms;
+    ///
+    /// assert_eq!(from_hms(10, 7, 29).nearest(Duration::minutes(15)), from_hms(10, 0, 0));
+    /// assert_eq!(from_hms(10, 7, 30).nearest(Duration::minutes(15)), from_hms(10, 15, 0));
+    /// assert_eq!(from_hms(23, 53, 0).nearest(Duration::minutes(15)), from_hms(0, 0, 0));
+    /// assert_eq!(from_hms(10, 7, 30).nearest(Duration::days(1)), from_hms(10, 7, 30));
+    /// # }
+    /// ~~~~
+    pub fn nearest(&self, duration: OldDuration) -> NaiveTime {
+        assert!(duration > OldDuration::zero(), "NaiveTime::nearest with non-positive duration");
+        if duration >= OldDuration::days(1) {
+            return *self;
+        }
+
+        const NANOS_PER_DAY: i64 = 86_400 * 1_000_000_000;
+        let span = duration.num_nanoseconds().unwrap(); // cannot overflow within a day
+        let nanos = i64::from(self.secs) * 1_000_000_000 + i64::from(self.frac);
+        let delta_down = nanos % span;
+        let mut rounded = if delta_down * 2 >= span {
+            nanos - delta_down + span
+        } else {
+            nanos - delta_down
+        };
+        if rounded >= NANOS_PER_DAY {
+            rounded = 0;
+        }
+        NaiveTime { secs: (rounded / 1_000_000_000) as u32, frac: (rounded % 1_000_000_000) as u32 }
+    }
+
+    /// Restricts the value to the inclusive range from `min` to `max`.
+    ///
+    /// Returns `min` if `self` is earlier than `min`, `max` if `self` is later than `max`,
+    /// and `self` otherwise.
+    ///
+    /// Panics if `min` is later than `max`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// let (min, max) = (NaiveTime::from_hms(9, 0, 0), NaiveTime::from_hms(17, 0, 0));
+    /// let t = NaiveTime::from_hms(12, 30, 0);
+    /// assert_eq!(t.clamp(min, max), t);
+    /// assert_eq!(NaiveTime::from_hms(23, 56, 4).clamp(min, max), max);
+    /// assert_eq!(NaiveTime::from_hms(8, 59, 59).clamp(min, max), min);
+    /// ~~~~
+    pub fn clamp(self, min: NaiveTime, max: NaiveTime) -> NaiveTime {
+        assert!(min <= max, "`min` should not be later than `max`");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
     /// Formats the time with the specified formatting items.
     /// Otherwise it is same to the ordinary [`format`](#method.format) method.
     ///
@@ -724,8 +888,8 @@ impl NaiveTime {
     /// assert_eq!(format!("{}", t.format_with_items(fmt)), "23:56:04");
     /// ~~~~
     #[inline]
-    pub fn format_with_items<'a, I>(&self, items: I) -> DelayedFormat<I>
-            where I: Iterator<Item=Item<'a>> + Clone {
+    pub fn format_with_items<'a, I, B>(&self, items: I) -> DelayedFormat<I>
+            where I: Iterator<Item=B> + Clone, B: Borrow<Item<'a>> {
         DelayedFormat::new(None, Some(*self), items)
     }
 
@@ -1512,6 +1676,88 @@ mod tests {
         assert_eq!(NaiveTime::from_hms_micro_opt(3, 5, 7, u32::MAX), None);
     }
 
+    #[test]
+    fn test_time_subsec_accessors() {
+        let t = NaiveTime::from_hms_nano(3, 5, 7, 987_654_321);
+        assert_eq!((t.millisecond(), t.microsecond()), (987, 987_654));
+        let t = NaiveTime::from_hms_nano(3, 5, 7, 999);
+        assert_eq!((t.millisecond(), t.microsecond()), (0, 0));
+        let t = NaiveTime::from_hms(3, 5, 7);
+        assert_eq!((t.millisecond(), t.microsecond()), (0, 0));
+
+        // leap second
+        let t = NaiveTime::from_hms_nano(23, 59, 59, 1_999_999_999);
+        assert_eq!((t.millisecond(), t.microsecond()), (1_999, 1_999_999));
+    }
+
+    #[test]
+    fn test_time_clamp() {
+        let hms = |h,m,s| NaiveTime::from_hms(h, m, s);
+        let (min, max) = (hms(9, 0, 0), hms(17, 0, 0));
+        assert_eq!(hms(12, 0, 0).clamp(min, max), hms(12, 0, 0));
+        assert_eq!(hms(0, 0, 0).clamp(min, max), min);
+        assert_eq!(hms(17, 0, 1).clamp(min, max), max);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(max.clamp(min, max), max);
+
+        // a leap second is later than the preceding second
+        let leap = NaiveTime::from_hms_milli(16, 59, 59, 1_500);
+        assert_eq!(leap.clamp(min, max), leap);
+        assert_eq!(max.clamp(min, leap), leap);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_time_clamp_invalid_range() {
+        NaiveTime::from_hms(12, 0, 0).clamp(NaiveTime::from_hms(17, 0, 0),
+                                            NaiveTime::from_hms(9, 0, 0));
+    }
+
+    #[test]
+    fn test_time_hmsn_tuple() {
+        for secs in 0..86_400 {
+            for &nano in &[0, 1, 999_999_999] {
+                let t = NaiveTime::from_num_seconds_from_midnight(secs, nano);
+                let (h, m, s, n) = t.to_hmsn();
+                assert_eq!((h, m, s, n), (t.hour(), t.minute(), t.second(), t.nanosecond()));
+                assert_eq!(NaiveTime::from_hmsn((h, m, s, n)), Some(t));
+            }
+        }
+
+        let leap = NaiveTime::from_hms_nano(23, 59, 59, 1_500_000_000);
+        assert_eq!(leap.to_hmsn(), (23, 59, 59, 1_500_000_000));
+        assert_eq!(NaiveTime::from_hmsn(leap.to_hmsn()), Some(leap));
+        assert_eq!(NaiveTime::from_hmsn((23, 59, 60, 0)), None);
+        assert_eq!(NaiveTime::from_hmsn((3, 5, 7, u32::MAX)), None);
+    }
+
+    #[test]
+    fn test_time_decimal_hours() {
+        use std::f64;
+
+        for secs in 0..86_400 {
+            for &nano in &[0, 1, 123_456_789, 999_999_999] {
+                let t = NaiveTime::from_num_seconds_from_midnight(secs, nano);
+                let hours = t.to_decimal_hours();
+                assert!(0.0 <= hours && hours < 24.0);
+                assert_eq!(NaiveTime::from_decimal_hours(hours), Some(t));
+            }
+        }
+
+        assert_eq!(NaiveTime::from_decimal_hours(0.0), Some(NaiveTime::from_hms(0, 0, 0)));
+        assert_eq!(NaiveTime::from_decimal_hours(0.25), Some(NaiveTime::from_hms(0, 15, 0)));
+        assert_eq!(NaiveTime::from_decimal_hours(23.999_999_999_999_996),
+                   Some(NaiveTime::from_hms_nano(23, 59, 59, 999_999_999)));
+        assert_eq!(NaiveTime::from_decimal_hours(24.0), None);
+        assert_eq!(NaiveTime::from_decimal_hours(-0.000_001), None);
+        assert_eq!(NaiveTime::from_decimal_hours(f64::NAN), None);
+        assert_eq!(NaiveTime::from_decimal_hours(f64::INFINITY), None);
+        assert_eq!(NaiveTime::from_decimal_hours(f64::NEG_INFINITY), None);
+
+        let leap = NaiveTime::from_hms_milli(23, 59, 59, 1_500);
+        assert!(leap.to_decimal_hours() > 24.0);
+    }
+
     #[test]
     fn test_time_hms() {
         assert_eq!(NaiveTime::from_hms(3, 5, 7).hour(), 3);
@@ -1637,6 +1883,51 @@ mod tests {
         assert_eq!(hmsm(3, 5, 6, 1_800) + Duration::milliseconds(400), hmsm(3, 5, 7, 200));
     }
 
+    #[test]
+    fn test_time_nearest() {
+        let hms = |h,m,s| NaiveTime::from_hms(h, m, s);
+        let hmsm = |h,m,s,milli| NaiveTime::from_hms_milli(h, m, s, milli);
+
+        assert_eq!(hms(10, 7, 30).nearest(Duration::minutes(15)), hms(10, 15, 0));
+        assert_eq!(hmsm(10, 7, 29, 999).nearest(Duration::minutes(15)), hms(10, 0, 0));
+        assert_eq!(hms(10, 15, 0).nearest(Duration::minutes(15)), hms(10, 15, 0));
+        assert_eq!(hms(0, 0, 0).nearest(Duration::minutes(15)), hms(0, 0, 0));
+        assert_eq!(hms(10, 7, 30).nearest(Duration::hours(1)), hms(10, 0, 0));
+        assert_eq!(hms(10, 30, 0).nearest(Duration::hours(1)), hms(11, 0, 0));
+        assert_eq!(hmsm(3, 5, 7, 499).nearest(Duration::seconds(1)), hms(3, 5, 7));
+        assert_eq!(hmsm(3, 5, 7, 500).nearest(Duration::seconds(1)), hms(3, 5, 8));
+        assert_eq!(hmsm(3, 5, 7, 123).nearest(Duration::milliseconds(10)), hmsm(3, 5, 7, 120));
+        assert_eq!(hms(7, 0, 0).nearest(Duration::minutes(7 * 60 + 1)), hms(7, 1, 0));
+
+        // boundaries that are not divisors of a day still count from the midnight
+        assert_eq!(hms(22, 0, 0).nearest(Duration::hours(7)), hms(21, 0, 0));
+        assert_eq!(hms(23, 0, 0).nearest(Duration::hours(7)), hms(21, 0, 0));
+
+        // wraps around to the midnight
+        assert_eq!(hms(23, 59, 59).nearest(Duration::minutes(1)), hms(0, 0, 0));
+        assert_eq!(hmsm(23, 59, 59, 1_500).nearest(Duration::seconds(1)), hms(0, 0, 0));
+
+        // leap seconds belong to the following second
+        assert_eq!(hmsm(3, 5, 59, 1_200).nearest(Duration::seconds(1)), hms(3, 6, 0));
+        assert_eq!(hmsm(3, 5, 59, 1_700).nearest(Duration::seconds(1)), hms(3, 6, 1));
+
+        // unmodified for a day or longer
+        assert_eq!(hms(10, 7, 30).nearest(Duration::hours(24)), hms(10, 7, 30));
+        assert_eq!(hms(10, 7, 30).nearest(Duration::weeks(1)), hms(10, 7, 30));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_time_nearest_zero() {
+        NaiveTime::from_hms(10, 7, 30).nearest(Duration::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_time_nearest_negative() {
+        NaiveTime::from_hms(10, 7, 30).nearest(Duration::minutes(-15));
+    }
+
     #[test]
     fn test_time_fmt() {
         assert_eq!(format!("{}", NaiveTime::from_hms_milli(23, 59, 59, 999)), "23:59:59.999");
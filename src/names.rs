@@ -0,0 +1,21 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! English names of months and weekdays, shared by formatting and date accessors.
+
+/// Abbreviated month names, indexed by the zero-based month.
+pub static SHORT_MONTHS: [&'static str; 12] =
+    ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// Full month names, indexed by the zero-based month.
+pub static LONG_MONTHS: [&'static str; 12] =
+    ["January", "February", "March", "April", "May", "June",
+     "July", "August", "September", "October", "November", "December"];
+
+/// Abbreviated weekday names, indexed by the number of days from Monday.
+pub static SHORT_WEEKDAYS: [&'static str; 7] =
+    ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Full weekday names, indexed by the number of days from Monday.
+pub static LONG_WEEKDAYS: [&'static str; 7] =
+    ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
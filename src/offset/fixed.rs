@@ -96,13 +96,85 @@ impl FixedOffset {
     pub fn utc_minus_local(&self) -> i32 {
         -self.local_minus_utc
     }
+
+    /// Returns an iterator over all offsets from UTC-12:00 to UTC+14:00
+    /// in the ascending order, in 15-minute steps (105 offsets in total).
+    ///
+    /// This covers every offset used by the current time zones,
+    /// which makes it suitable for listing the choices of offset.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::FixedOffset;
+    ///
+    /// let mut offsets = FixedOffset::all_standard_offsets();
+    /// assert_eq!(offsets.len(), 105);
+    /// assert_eq!(offsets.next(), Some(FixedOffset::west(12 * 3600)));
+    /// assert_eq!(offsets.next(), Some(FixedOffset::west(11 * 3600 + 45 * 60)));
+    /// assert!(FixedOffset::all_standard_offsets().any(|off| off == FixedOffset::east(20700)));
+    /// ~~~~
+    pub fn all_standard_offsets() -> FixedOffsets {
+        FixedOffsets { next: -12 * 3600, last: 14 * 3600, step: 15 * 60 }
+    }
+
+    /// Returns an iterator over all whole-hour offsets from UTC-12:00 to UTC+14:00
+    /// in the ascending order (27 offsets in total).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::FixedOffset;
+    ///
+    /// let offsets: Vec<_> = FixedOffset::all_whole_hour_offsets().collect();
+    /// assert_eq!(offsets.len(), 27);
+    /// assert_eq!(offsets[12], FixedOffset::east(0));
+    /// assert_eq!(offsets[26], FixedOffset::east(14 * 3600));
+    /// ~~~~
+    pub fn all_whole_hour_offsets() -> FixedOffsets {
+        FixedOffsets { next: -12 * 3600, last: 14 * 3600, step: 3600 }
+    }
+}
+
+/// An iterator over the evenly spaced [`FixedOffset`](./struct.FixedOffset.html)s,
+/// as returned by [`FixedOffset::all_standard_offsets`
+/// ](./struct.FixedOffset.html#method.all_standard_offsets).
+#[derive(Debug, Clone)]
+pub struct FixedOffsets {
+    next: i32,
+    last: i32,
+    step: i32,
 }
 
+impl Iterator for FixedOffsets {
+    type Item = FixedOffset;
+
+    fn next(&mut self) -> Option<FixedOffset> {
+        if self.next > self.last { return None; }
+        let offset = FixedOffset { local_minus_utc: self.next };
+        self.next += self.step;
+        Some(offset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.next > self.last {
+            0
+        } else {
+            ((self.last - self.next) / self.step + 1) as usize
+        };
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for FixedOffsets {}
+
 impl TimeZone for FixedOffset {
     type Offset = FixedOffset;
 
     fn from_offset(offset: &FixedOffset) -> FixedOffset { *offset }
 
+    fn name(&self) -> String { self.to_string() }
+
     fn offset_from_local_date(&self, _local: &NaiveDate) -> LocalResult<FixedOffset> {
         LocalResult::Single(*self)
     }
@@ -222,5 +294,35 @@ mod tests {
         assert_eq!(format!("{:?}", FixedOffset::west(86399).ymd(2012, 3, 4).and_hms(5, 6, 7)),
                    "2012-03-04T05:06:07-23:59:59".to_string());
     }
-}
 
+    #[test]
+    fn test_fixed_offset_all_offsets() {
+        let offsets: Vec<_> = FixedOffset::all_standard_offsets().collect();
+        assert_eq!(offsets.len(), 105);
+        assert_eq!(offsets.first(), Some(&FixedOffset::west(12 * 3600)));
+        assert_eq!(offsets.last(), Some(&FixedOffset::east(14 * 3600)));
+        for w in offsets.windows(2) {
+            assert_eq!(w[1].local_minus_utc() - w[0].local_minus_utc(), 15 * 60);
+        }
+
+        // well-known offsets which are not whole hours
+        for &secs in &[-(9 * 3600 + 30 * 60), 3 * 3600 + 30 * 60, 5 * 3600 + 45 * 60,
+                       8 * 3600 + 45 * 60, 12 * 3600 + 45 * 60] {
+            assert!(offsets.contains(&FixedOffset::east(secs)), "{}", secs);
+        }
+
+        let hours: Vec<_> = FixedOffset::all_whole_hour_offsets().collect();
+        assert_eq!(hours.len(), 27);
+        assert_eq!(hours, offsets.iter().cloned()
+                                 .filter(|off| off.local_minus_utc() % 3600 == 0)
+                                 .collect::<Vec<_>>());
+
+        let mut it = FixedOffset::all_whole_hour_offsets();
+        assert_eq!(it.len(), 27);
+        assert_eq!(it.nth(25), Some(FixedOffset::east(13 * 3600)));
+        assert_eq!(it.len(), 1);
+        assert_eq!(it.next(), Some(FixedOffset::east(14 * 3600)));
+        assert_eq!(it.len(), 0);
+        assert_eq!(it.next(), None);
+    }
+}
@@ -97,6 +97,8 @@ impl TimeZone for Local {
 
     fn from_offset(_offset: &FixedOffset) -> Local { Local }
 
+    fn name(&self) -> String { "Local".to_string() }
+
     // they are easier to define in terms of the finished date and time unlike other offsets
     fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
         self.from_local_date(local).map(|date| *date.offset())
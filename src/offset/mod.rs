@@ -19,6 +19,7 @@
 //! An `TimeZone` instance can be reconstructed from the corresponding `Offset` instance.
 
 use std::fmt;
+use oldtime::Duration as OldDuration;
 
 use format::{parse, ParseResult, Parsed, StrftimeItems};
 use naive::{NaiveDate, NaiveDateTime, NaiveTime};
@@ -179,6 +180,27 @@ impl<T: fmt::Debug> LocalResult<T> {
     }
 }
 
+/// A resolution strategy for the ambiguous local time which picks the earliest result.
+///
+/// See [`NaiveDateTime::with_timezone_and_resolve`](../naive/struct.NaiveDateTime.html#method.with_timezone_and_resolve).
+pub fn resolve_earliest<T>(earliest: T, _latest: T) -> Option<T> {
+    Some(earliest)
+}
+
+/// A resolution strategy for the ambiguous local time which picks the latest result.
+///
+/// See [`NaiveDateTime::with_timezone_and_resolve`](../naive/struct.NaiveDateTime.html#method.with_timezone_and_resolve).
+pub fn resolve_latest<T>(_earliest: T, latest: T) -> Option<T> {
+    Some(latest)
+}
+
+/// A resolution strategy for the ambiguous local time which rejects it.
+///
+/// See [`NaiveDateTime::with_timezone_and_resolve`](../naive/struct.NaiveDateTime.html#method.with_timezone_and_resolve).
+pub fn resolve_error<T>(_earliest: T, _latest: T) -> Option<T> {
+    None
+}
+
 /// The offset from the local time to UTC.
 pub trait Offset: Sized + Clone + fmt::Debug {
     /// Returns the fixed offset from UTC to the local time stored.
@@ -419,6 +441,24 @@ pub trait TimeZone: Sized + Clone {
         parsed.to_datetime_with_timezone(self)
     }
 
+    /// Returns the name of the time zone for the display purpose,
+    /// e.g. `UTC` for `Utc`, `Local` for `Local` and `+09:00` for `FixedOffset`.
+    ///
+    /// The default implementation returns `Unknown`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Utc, FixedOffset, TimeZone};
+    ///
+    /// assert_eq!(Utc.name(), "UTC");
+    /// assert_eq!(FixedOffset::east(19800).name(), "+05:30");
+    /// assert_eq!(FixedOffset::west(3600).name(), "-01:00");
+    /// ~~~~
+    fn name(&self) -> String {
+        "Unknown".to_string()
+    }
+
     /// Reconstructs the time zone from the offset.
     fn from_offset(offset: &Self::Offset) -> Self;
 
@@ -442,6 +482,34 @@ pub trait TimeZone: Sized + Clone {
             .map(|offset| DateTime::from_utc(*local - offset.fix(), offset))
     }
 
+    /// Returns an iterator over the local date and time from `start`,
+    /// advanced by `step` in the local (wall clock) time at a time.
+    ///
+    /// The local time can be skipped or repeated by the transitions like
+    /// the daylight saving time. The iterator skips the local time which does not exist,
+    /// and yields only the earliest `DateTime` for the ambiguous local time,
+    /// so every item is a valid `DateTime` for the corresponding local time.
+    /// The iteration ends when the local time would overflow.
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, FixedOffset, TimeZone, Duration};
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// let start = NaiveDate::from_ymd(2015, 9, 5).and_hms(22, 0, 0);
+    /// let times: Vec<_> = tz.local_steps(start, Duration::hours(1)).take(3).collect();
+    /// assert_eq!(times, [tz.ymd(2015, 9, 5).and_hms(22, 0, 0),
+    ///                    tz.ymd(2015, 9, 5).and_hms(23, 0, 0),
+    ///                    tz.ymd(2015, 9, 6).and_hms(0, 0, 0)]);
+    /// ~~~~
+    fn local_steps(&self, start: NaiveDateTime, step: OldDuration) -> LocalSteps<Self> {
+        assert!(step != OldDuration::zero(), "`step` should not be zero");
+        LocalSteps { tz: self.clone(), next: Some(start), step: step }
+    }
+
     /// Creates the offset for given UTC `NaiveDate`. This cannot fail.
     fn offset_from_utc_date(&self, utc: &NaiveDate) -> Self::Offset;
 
@@ -461,16 +529,117 @@ pub trait TimeZone: Sized + Clone {
     }
 }
 
+/// An iterator over the valid local date and time advanced by a fixed step,
+/// as returned by [`TimeZone::local_steps`](./trait.TimeZone.html#method.local_steps).
+#[derive(Clone)]
+pub struct LocalSteps<Tz: TimeZone> {
+    tz: Tz,
+    next: Option<NaiveDateTime>,
+    step: OldDuration,
+}
+
+impl<Tz: TimeZone> Iterator for LocalSteps<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        loop {
+            let local = try_opt!(self.next);
+            self.next = local.checked_add_signed(self.step);
+            // otherwise the local time is in the gap, try the next one
+            if let Some(dt) = self.tz.from_local_datetime(&local).earliest() {
+                return Some(dt);
+            }
+        }
+    }
+}
+
+impl<Tz: TimeZone> fmt::Debug for LocalSteps<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LocalSteps").field("next", &self.next).field("step", &self.step).finish()
+    }
+}
+
 mod fixed;
 #[cfg(feature = "clock")]
 mod local;
 mod utc;
 
-pub use self::fixed::FixedOffset;
+pub use self::fixed::{FixedOffset, FixedOffsets};
 #[cfg(feature = "clock")]
 pub use self::local::Local;
 pub use self::utc::Utc;
 
+/// Returns the current date and time in UTC.
+/// This is same to [`Utc::now()`](./struct.Utc.html#method.now).
+///
+/// # Example
+///
+/// ~~~~
+/// let now = chrono::utc_now();
+/// assert!(now >= chrono::Utc::now() - chrono::Duration::seconds(60));
+/// ~~~~
+#[cfg(feature = "clock")]
+#[inline]
+pub fn utc_now() -> DateTime<Utc> {
+    Utc::now()
+}
+
+/// Returns the current date and time in the local time zone.
+/// This is same to [`Local::now()`](./struct.Local.html#method.now).
+///
+/// # Example
+///
+/// ~~~~
+/// let now = chrono::local_now();
+/// assert!(now >= chrono::Local::now() - chrono::Duration::seconds(60));
+/// ~~~~
+#[cfg(feature = "clock")]
+#[inline]
+pub fn local_now() -> DateTime<Local> {
+    Local::now()
+}
+
+/// A time zone with the UTC+1 standard time and the UTC+2 summer time in 2015,
+/// for testing the nonexistent and ambiguous local times in other modules.
+///
+/// The local time from 02:00 to 03:00 on March 29 does not exist,
+/// and the local time from 02:00 to 03:00 on October 25 happens twice.
+#[cfg(test)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TestCet;
+
+#[cfg(test)]
+impl TimeZone for TestCet {
+    type Offset = FixedOffset;
+
+    fn from_offset(_offset: &FixedOffset) -> TestCet { TestCet }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        self.offset_from_local_datetime(&local.and_hms(0, 0, 0))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        let valid = |off: FixedOffset| self.offset_from_utc_datetime(&(*local - off)) == off;
+        match (valid(FixedOffset::east(7200)), valid(FixedOffset::east(3600))) {
+            (true, true) => LocalResult::Ambiguous(FixedOffset::east(7200),
+                                                   FixedOffset::east(3600)),
+            (true, false) => LocalResult::Single(FixedOffset::east(7200)),
+            (false, true) => LocalResult::Single(FixedOffset::east(3600)),
+            (false, false) => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        let summer = NaiveDate::from_ymd(2015, 3, 29).and_hms(1, 0, 0) <= *utc &&
+                     *utc < NaiveDate::from_ymd(2015, 10, 25).and_hms(1, 0, 0);
+        FixedOffset::east(if summer { 7200 } else { 3600 })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -509,4 +678,70 @@ mod tests {
         Utc.timestamp_nanos(i64::default());
         Utc.timestamp_nanos(i64::min_value());
     }
+
+    #[test]
+    fn test_timezone_name() {
+        // a time zone with the default name
+        #[derive(Clone)]
+        struct Nameless;
+
+        impl TimeZone for Nameless {
+            type Offset = Utc;
+
+            fn from_offset(_offset: &Utc) -> Nameless { Nameless }
+            fn offset_from_local_date(&self, _local: &NaiveDate) -> LocalResult<Utc> {
+                LocalResult::Single(Utc)
+            }
+            fn offset_from_local_datetime(&self, _local: &NaiveDateTime) -> LocalResult<Utc> {
+                LocalResult::Single(Utc)
+            }
+            fn offset_from_utc_date(&self, _utc: &NaiveDate) -> Utc { Utc }
+            fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> Utc { Utc }
+        }
+
+        assert_eq!(Nameless.name(), "Unknown");
+        assert_eq!(Utc.name(), "UTC");
+        assert_eq!(FixedOffset::east(0).name(), "+00:00");
+        assert_eq!(FixedOffset::east(9 * 3600 + 30 * 60).name(), "+09:30");
+        assert_eq!(FixedOffset::west(86_399).name(), "-23:59:59");
+        #[cfg(feature = "clock")]
+        assert_eq!(Local.name(), "Local");
+    }
+
+    #[test]
+    fn test_local_steps() {
+        let ymdhms = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s);
+        let utc_hours = |start, step| {
+            TestCet.local_steps(start, step).take(4)
+               .map(|dt| dt.naive_utc().format("%d %H:%M").to_string())
+               .collect::<Vec<_>>()
+        };
+
+        // 02:00 to 03:00 does not exist
+        assert_eq!(utc_hours(ymdhms(2015, 3, 29, 0, 0, 0), OldDuration::hours(1)),
+                   ["28 23:00", "29 00:00", "29 01:00", "29 02:00"]);
+        assert_eq!(utc_hours(ymdhms(2015, 3, 29, 1, 30, 0), OldDuration::minutes(30)),
+                   ["29 00:30", "29 01:00", "29 01:30", "29 02:00"]);
+        assert_eq!(utc_hours(ymdhms(2015, 3, 29, 4, 0, 0), OldDuration::hours(-1)),
+                   ["29 02:00", "29 01:00", "29 00:00", "28 23:00"]);
+
+        // 02:00 to 03:00 happens twice
+        assert_eq!(utc_hours(ymdhms(2015, 10, 25, 0, 0, 0), OldDuration::hours(1)),
+                   ["24 22:00", "24 23:00", "25 00:00", "25 02:00"]);
+
+        // ends at the end of the representable range
+        let start = ::naive::MAX_DATE.and_hms(22, 0, 0);
+        assert_eq!(Utc.local_steps(start, OldDuration::hours(1)).count(), 2);
+        assert_eq!(format!("{:?}", Utc.local_steps(start, OldDuration::hours(1))),
+                   format!("LocalSteps {{ next: Some({:?}), step: {:?} }}",
+                           start, OldDuration::hours(1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_local_steps_zero_step() {
+        // a zero step would never get past the nonexistent local time
+        let start = NaiveDate::from_ymd(2015, 3, 29).and_hms(2, 30, 0);
+        TestCet.local_steps(start, OldDuration::zero());
+    }
 }
@@ -50,6 +50,8 @@ impl TimeZone for Utc {
 
     fn from_offset(_state: &Utc) -> Utc { Utc }
 
+    fn name(&self) -> String { "UTC".to_string() }
+
     fn offset_from_local_date(&self, _local: &NaiveDate) -> LocalResult<Utc> {
         LocalResult::Single(Utc)
     }
@@ -0,0 +1,270 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Half-open interval of timezone-aware date and time.
+
+use std::{cmp, fmt};
+use oldtime::Duration as OldDuration;
+
+use offset::TimeZone;
+use DateTime;
+
+/// A half-open interval of time, from `start` (inclusive) to `end` (exclusive).
+///
+/// It is always guaranteed that `start <= end`.
+/// A period with `start == end` is empty and contains no instant.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Utc, TimeZone, Period, Duration};
+///
+/// let meeting = Period::new(Utc.ymd(2015, 9, 5).and_hms(9, 0, 0),
+///                           Utc.ymd(2015, 9, 5).and_hms(10, 30, 0)).unwrap();
+/// assert_eq!(meeting.duration(), Duration::minutes(90));
+/// assert!(meeting.contains(&Utc.ymd(2015, 9, 5).and_hms(9, 0, 0)));
+/// assert!(!meeting.contains(&Utc.ymd(2015, 9, 5).and_hms(10, 30, 0)));
+/// ~~~~
+#[derive(Clone)]
+pub struct Period<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> Period<Tz> {
+    /// Makes a new `Period` from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// Returns `None` when `start` is later than `end`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Utc, TimeZone, Period};
+    ///
+    /// let (a, b) = (Utc.ymd(2015, 9, 5).and_hms(9, 0, 0), Utc.ymd(2015, 9, 5).and_hms(17, 0, 0));
+    /// assert!(Period::new(a, b).is_some());
+    /// assert!(Period::new(a, a).is_some());
+    /// assert!(Period::new(b, a).is_none());
+    /// ~~~~
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Option<Period<Tz>> {
+        if start <= end {
+            Some(Period { start: start, end: end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the start of the period, which is included in the period.
+    #[inline]
+    pub fn start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    /// Returns the end of the period, which is not included in the period.
+    #[inline]
+    pub fn end(&self) -> &DateTime<Tz> {
+        &self.end
+    }
+
+    /// Returns `true` if the period contains no instant, i.e. `start == end`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the length of the period.
+    #[inline]
+    pub fn duration(&self) -> OldDuration {
+        self.end.clone().signed_duration_since(self.start.clone())
+    }
+
+    /// Returns `true` if `dt` is within the period, i.e. `start <= dt < end`.
+    #[inline]
+    pub fn contains(&self, dt: &DateTime<Tz>) -> bool {
+        self.start <= *dt && *dt < self.end
+    }
+
+    /// Returns `true` if two periods have at least one instant in common.
+    ///
+    /// Adjacent periods (one ending exactly when the other starts) do not overlap,
+    /// and empty periods do not overlap anything.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Utc, TimeZone, Period};
+    ///
+    /// let period = |h1, h2| Period::new(Utc.ymd(2015, 9, 5).and_hms(h1, 0, 0),
+    ///                                   Utc.ymd(2015, 9, 5).and_hms(h2, 0, 0)).unwrap();
+    ///
+    /// assert!(period(9, 12).overlaps(&period(11, 13)));
+    /// assert!(!period(9, 12).overlaps(&period(12, 13)));
+    /// ~~~~
+    #[inline]
+    pub fn overlaps(&self, other: &Period<Tz>) -> bool {
+        !self.is_empty() && !other.is_empty() &&
+            self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the period common to both periods, or `None` if they do not overlap.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Utc, TimeZone, Period};
+    ///
+    /// let period = |h1, h2| Period::new(Utc.ymd(2015, 9, 5).and_hms(h1, 0, 0),
+    ///                                   Utc.ymd(2015, 9, 5).and_hms(h2, 0, 0)).unwrap();
+    ///
+    /// assert_eq!(period(9, 12).intersection(&period(11, 13)), Some(period(11, 12)));
+    /// assert_eq!(period(9, 12).intersection(&period(12, 13)), None);
+    /// ~~~~
+    pub fn intersection(&self, other: &Period<Tz>) -> Option<Period<Tz>> {
+        if self.overlaps(other) {
+            Some(Period { start: cmp::max(self.start.clone(), other.start.clone()),
+                          end: cmp::min(self.end.clone(), other.end.clone()) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest period covering both periods,
+    /// or `None` if there is a gap between them.
+    ///
+    /// Adjacent periods (one ending exactly when the other starts) are joined.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Utc, TimeZone, Period};
+    ///
+    /// let period = |h1, h2| Period::new(Utc.ymd(2015, 9, 5).and_hms(h1, 0, 0),
+    ///                                   Utc.ymd(2015, 9, 5).and_hms(h2, 0, 0)).unwrap();
+    ///
+    /// assert_eq!(period(9, 12).union(&period(11, 13)), Some(period(9, 13)));
+    /// assert_eq!(period(9, 12).union(&period(12, 13)), Some(period(9, 13)));
+    /// assert_eq!(period(9, 12).union(&period(13, 14)), None);
+    /// ~~~~
+    pub fn union(&self, other: &Period<Tz>) -> Option<Period<Tz>> {
+        if self.start <= other.end && other.start <= self.end {
+            Some(Period { start: cmp::min(self.start.clone(), other.start.clone()),
+                          end: cmp::max(self.end.clone(), other.end.clone()) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<Tz: TimeZone> Copy for Period<Tz> where <Tz as TimeZone>::Offset: Copy {}
+
+impl<Tz: TimeZone, Tz2: TimeZone> PartialEq<Period<Tz2>> for Period<Tz> {
+    fn eq(&self, other: &Period<Tz2>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<Tz: TimeZone> Eq for Period<Tz> {}
+
+/// The `Debug` output of the period `p` is `start..end` of the `Debug` output of each end.
+impl<Tz: TimeZone> fmt::Debug for Period<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Period;
+    use offset::{TimeZone, Utc, FixedOffset};
+    use oldtime::Duration;
+
+    fn period(start: (u32, u32), end: (u32, u32)) -> Period<Utc> {
+        Period::new(Utc.ymd(2016, 2, 29).and_hms(start.0, start.1, 0),
+                    Utc.ymd(2016, 2, 29).and_hms(end.0, end.1, 0)).unwrap()
+    }
+
+    #[test]
+    fn test_period_new() {
+        let dt = Utc.ymd(2016, 2, 29).and_hms(12, 0, 0);
+        assert!(Period::new(dt, dt).is_some());
+        assert!(Period::new(dt, dt + Duration::nanoseconds(1)).is_some());
+        assert!(Period::new(dt + Duration::nanoseconds(1), dt).is_none());
+    }
+
+    #[test]
+    fn test_period_empty() {
+        let p = period((12, 0), (12, 0));
+        assert!(p.is_empty());
+        assert_eq!(p.duration(), Duration::zero());
+        assert!(!p.contains(p.start()));
+        assert!(!p.overlaps(&p));
+        assert!(!p.overlaps(&period((11, 0), (13, 0))));
+        assert!(!period((11, 0), (13, 0)).overlaps(&p));
+        assert_eq!(p.intersection(&period((11, 0), (13, 0))), None);
+        assert_eq!(p.union(&period((11, 0), (13, 0))), Some(period((11, 0), (13, 0))));
+        assert_eq!(p.union(&p), Some(p));
+    }
+
+    #[test]
+    fn test_period_contains() {
+        let p = period((9, 0), (17, 0));
+        assert!(!p.is_empty());
+        assert_eq!(p.duration(), Duration::hours(8));
+        assert!(p.contains(&Utc.ymd(2016, 2, 29).and_hms(9, 0, 0)));
+        assert!(p.contains(&Utc.ymd(2016, 2, 29).and_hms_nano(16, 59, 59, 999_999_999)));
+        assert!(!p.contains(&Utc.ymd(2016, 2, 29).and_hms(17, 0, 0)));
+        assert!(!p.contains(&Utc.ymd(2016, 2, 29).and_hms_nano(8, 59, 59, 999_999_999)));
+    }
+
+    #[test]
+    fn test_period_allen_relations() {
+        let x = period((10, 0), (12, 0));
+
+        // (relation of x to y, y, overlaps, intersection, union)
+        let cases = [
+            ("before",        period((13, 0), (14, 0)), false, None, None),
+            ("meets",         period((12, 0), (14, 0)), false, None,
+                              Some(period((10, 0), (14, 0)))),
+            ("overlaps",      period((11, 0), (14, 0)), true, Some(period((11, 0), (12, 0))),
+                              Some(period((10, 0), (14, 0)))),
+            ("starts",        period((10, 0), (14, 0)), true, Some(x),
+                              Some(period((10, 0), (14, 0)))),
+            ("during",        period((9, 0), (14, 0)), true, Some(x),
+                              Some(period((9, 0), (14, 0)))),
+            ("finishes",      period((9, 0), (12, 0)), true, Some(x),
+                              Some(period((9, 0), (12, 0)))),
+            ("equals",        x, true, Some(x), Some(x)),
+            ("finished by",   period((11, 0), (12, 0)), true, Some(period((11, 0), (12, 0))),
+                              Some(x)),
+            ("contains",      period((10, 30), (11, 30)), true,
+                              Some(period((10, 30), (11, 30))), Some(x)),
+            ("started by",    period((10, 0), (11, 0)), true, Some(period((10, 0), (11, 0))),
+                              Some(x)),
+            ("overlapped by", period((9, 0), (11, 0)), true, Some(period((10, 0), (11, 0))),
+                              Some(period((9, 0), (12, 0)))),
+            ("met by",        period((9, 0), (10, 0)), false, None,
+                              Some(period((9, 0), (12, 0)))),
+            ("after",         period((8, 0), (9, 0)), false, None, None),
+        ];
+
+        for &(name, y, overlaps, intersection, union) in &cases {
+            assert_eq!(x.overlaps(&y), overlaps, "overlaps, x {} y", name);
+            assert_eq!(y.overlaps(&x), overlaps, "overlaps, y {} x", name);
+            assert_eq!(x.intersection(&y), intersection, "intersection, x {} y", name);
+            assert_eq!(y.intersection(&x), intersection, "intersection, y {} x", name);
+            assert_eq!(x.union(&y), union, "union, x {} y", name);
+            assert_eq!(y.union(&x), union, "union, y {} x", name);
+        }
+    }
+
+    #[test]
+    fn test_period_offsets() {
+        // periods are compared by the instants, regardless of the offset
+        let tz = FixedOffset::east(9 * 3600);
+        let p = Period::new(tz.ymd(2016, 2, 29).and_hms(18, 0, 0),
+                            tz.ymd(2016, 2, 29).and_hms(20, 0, 0)).unwrap();
+        assert!(p.contains(&tz.from_utc_datetime(&period((9, 0), (9, 0)).start().naive_utc())));
+        assert_eq!(p, period((9, 0), (11, 0)));
+        assert_eq!(format!("{:?}", p), "2016-02-29T18:00:00+09:00..2016-02-29T20:00:00+09:00");
+    }
+}